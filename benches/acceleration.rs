@@ -0,0 +1,102 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Benchmarks the per-sample driver overhead of the acceleration read path
+//! and of polling the fifo entry count.
+//!
+//! The bus is replaced by an in-memory loopback so only the time spent in the
+//! driver itself, framing the transfer and decoding the sample, is measured.
+
+use adxl345_driver::{
+    spi::{SpiBus, SpiTransport},
+    transport::{self, Transport},
+    Adxl345Reader, Result,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+/// SPI bus which answers every read with the same fixed bytes.
+struct LoopbackSpi;
+
+impl SpiBus for LoopbackSpi {
+    /// Kept out of line with the buffers passed through `black_box()` so the
+    /// optimizer can not see through the bus as it can not with a real one.
+    #[inline(never)]
+    fn transfer(&self, read_buf: &mut [u8], write_buf: &[u8]) -> Result {
+        black_box(write_buf);
+        for (i, byte) in black_box(read_buf).iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        Ok(())
+    }
+    fn write(&mut self, _write_buf: &[u8]) -> Result {
+        Ok(())
+    }
+}
+
+/// Register transport which answers every read with the same fixed bytes.
+struct LoopbackRegisters;
+
+impl Transport for LoopbackRegisters {
+    #[inline(never)]
+    fn read_register(&self, register: u8) -> adxl345_driver::AdxlResult<u8> {
+        Ok(black_box(register))
+    }
+    fn write_register(&mut self, _register: u8, _byte: u8) -> Result {
+        Ok(())
+    }
+    #[inline(never)]
+    fn read_burst(&self, start: u8, buf: &mut [u8]) -> Result {
+        for (i, byte) in black_box(buf).iter_mut().enumerate() {
+            *byte = start + i as u8;
+        }
+        Ok(())
+    }
+}
+
+fn acceleration(c: &mut Criterion) {
+    let spi = transport::Device::new(SpiTransport::new(LoopbackSpi)).unwrap();
+    c.bench_function("spi acceleration", |b| {
+        b.iter(|| black_box(spi.acceleration().unwrap()))
+    });
+    let registers = transport::Device::new(LoopbackRegisters).unwrap();
+    c.bench_function("transport acceleration", |b| {
+        b.iter(|| black_box(registers.acceleration().unwrap()))
+    });
+}
+
+/// Compares [fifo_entries()] with decoding the whole [fifo_status()] just to
+/// get the entry count.
+///
+/// [fifo_entries()]: adxl345_driver::Adxl345Reader::fifo_entries
+/// [fifo_status()]: adxl345_driver::Adxl345Reader::fifo_status
+fn fifo_entries(c: &mut Criterion) {
+    let registers = transport::Device::new(LoopbackRegisters).unwrap();
+    c.bench_function("transport fifo_entries", |b| {
+        b.iter(|| black_box(registers.fifo_entries().unwrap()))
+    });
+    c.bench_function("transport fifo_status entries", |b| {
+        b.iter(|| black_box(registers.fifo_status().unwrap().entries()))
+    });
+}
+
+criterion_group!(benches, acceleration, fifo_entries);
+criterion_main!(benches);
@@ -48,7 +48,6 @@ use rppal::system::DeviceInfo;
 use std::{
     sync::atomic::{AtomicBool, Ordering},
     sync::Arc,
-    thread::sleep,
     time::Duration,
 };
 
@@ -56,6 +55,8 @@ use std::{
 const SCALE_MULTIPLIER: f64 = 0.004;
 /// Average Earth gravity in m/s²
 const EARTH_GRAVITY_MS2: f64 = 9.80665;
+/// Rate in Hz at which samples are displayed.
+const SAMPLE_RATE_HZ: f64 = 10.0;
 
 /// Entry point of example.
 fn main() -> Result<()> {
@@ -84,10 +85,9 @@ fn main() -> Result<()> {
     })
     .context("Error setting Ctrl-C handler")?;
     // Loop until Ctrl-C is received.
-    while running.load(Ordering::SeqCst) {
-        let (x, y, z) = adxl345
-            .acceleration()
-            .context("Failed to get acceleration data")?;
+    let period = Duration::from_secs_f64(1.0 / SAMPLE_RATE_HZ);
+    for sample in adxl345.samples_until(running, period) {
+        let (x, y, z) = sample.context("Failed to get acceleration data")?;
         let x = x as f64 * SCALE_MULTIPLIER * EARTH_GRAVITY_MS2;
         let y = y as f64 * SCALE_MULTIPLIER * EARTH_GRAVITY_MS2;
         let z = z as f64 * SCALE_MULTIPLIER * EARTH_GRAVITY_MS2;
@@ -95,7 +95,6 @@ fn main() -> Result<()> {
             "axis: {{'x': {:1.4}, 'y': {:1.4}, 'z': {:1.4}}} m/s²",
             x, y, z
         );
-        sleep(Duration::from_millis(100));
     }
     // Set measurement mode off.
     adxl345
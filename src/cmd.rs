@@ -58,11 +58,746 @@
 //!
 //! [ADXL345 Datasheet]: https://www.analog.com/media/en/technical-documentation/data-sheets/ADXL345.pdf
 
-use crate::{AdxlError, AdxlResult, Result};
-use std::convert::{TryFrom, TryInto};
+use crate::{
+    csv, Adxl345Config, AdxlError, AdxlResult, Counts, CsvUnits, RegisterDump, Result, SamplesUntil,
+};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    io::Write,
+    sync::{atomic::AtomicBool, Arc},
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+/// Fixed device ID value returned by the DEVID register of an ADXL345/ADXL346.
+pub const DEVICE_ID: u8 = 0xe5;
+
+/// Decodes one sample from the 6 data register bytes (DATAX0 through DATAZ1).
+///
+/// This is the same decoding used by [acceleration()], and so also by the
+/// fifo reads, so raw captures can be decoded offline with the exact same
+/// logic.
+/// See [acceleration()] for the byte order.
+///
+/// Slices of a capture can be passed with `try_into()`, which fails instead of
+/// panicking when fewer than 6 bytes are left.
+///
+/// ## Arguments
+/// * `bytes` - Data register bytes starting with DATAX0.
+///
+/// [acceleration()]: trait.Adxl345Reader.html#tymethod.acceleration
+pub fn parse_sample(bytes: &[u8; 6]) -> (i16, i16, i16) {
+    (
+        i16::from_le_bytes([bytes[0], bytes[1]]),
+        i16::from_le_bytes([bytes[2], bytes[3]]),
+        i16::from_le_bytes([bytes[4], bytes[5]]),
+    )
+}
+
+/// Scale factor of the offset adjustment registers in g/LSB (15.6 mg/LSB).
+const OFFSET_SCALE_G: f64 = 0.0156;
+
+/// Converts an offset adjustment in g into register counts.
+///
+/// Rounds to the nearest count and clamps to the `i8` range.
+fn offset_g_to_counts(g: f64) -> i8 {
+    (g / OFFSET_SCALE_G)
+        .round()
+        .clamp(i8::MIN as f64, i8::MAX as f64) as i8
+}
+
+/// Converts a physical value into an unsigned register count.
+///
+/// Rounds to the nearest count and returns [AdxlError::ValueOutOfRange]
+/// instead of wrapping or saturating when the count does not fit in a byte.
+///
+/// ## Arguments
+/// * `requested` - Physical value to be converted.
+/// * `scale` - Scale factor of the register in the same unit per LSB.
+///
+/// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+fn checked_count(requested: f64, scale: f64) -> AdxlResult<u8> {
+    let count = (requested / scale).round();
+    if (0.0..=u8::MAX as f64).contains(&count) {
+        Ok(count as u8)
+    } else {
+        Err(AdxlError::ValueOutOfRange {
+            requested,
+            max: u8::MAX as f64 * scale,
+        })
+    }
+}
+
+/// Converts a physical value into an unsigned register count clamping it to
+/// the register range instead of returning an error.
+///
+/// Rounds to the nearest count and negative values clamp to 0.
+///
+/// ## Arguments
+/// * `requested` - Physical value to be converted.
+/// * `scale` - Scale factor of the register in the same unit per LSB.
+fn saturating_count(requested: f64, scale: f64) -> u8 {
+    (requested / scale).round().max(0.0).min(u8::MAX as f64) as u8
+}
+
+/// Integer square root rounded down.
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Integer arctangent of `num / den` in centidegrees (0 to 9000).
+///
+/// Uses the `π/4·r + 0.273·r·(1 - r)` approximation, which is within about
+/// 0.25° over the first octant, in Q15 fixed point and mirrors the result for
+/// ratios above 1.
+fn atan_centidegrees(num: u64, den: u64) -> i16 {
+    if num == 0 {
+        return 0;
+    }
+    if num > den {
+        return 9000 - atan_centidegrees(den, num);
+    }
+    let r = ((num << 15) / den) as i64;
+    ((4500 * r + 1564 * r * (32768 - r) / 32768 + (1 << 14)) >> 15) as i16
+}
+
+/// Splits a byte into an iterator of its set single bits from MSB to LSB.
+fn set_bits(bits: u8) -> impl Iterator<Item = u8> {
+    (0..8u8)
+        .rev()
+        .map(|bit| 1 << bit)
+        .filter(move |mask| bits & mask != 0)
+}
+
+/// Angle in degrees of the `a` axis from the plane of the `b` and `c` axes.
+///
+/// Between -90° and 90° with 0° meaning the axis is horizontal.
+pub(crate) fn axis_angle_deg(a: f64, b: f64, c: f64) -> f64 {
+    a.atan2((b * b + c * c).sqrt()).to_degrees()
+}
+
+/// Scales a raw sample to g using the data format it was read with.
+pub(crate) fn sample_to_g(sample: (i16, i16, i16), format: DataFormat) -> (f64, f64, f64) {
+    let scale = format.scale_factor();
+    let g = |raw: i16| format.sign_extend_if_needed(raw) as f64 * scale;
+    (g(sample.0), g(sample.1), g(sample.2))
+}
+
+/// Checks a range of registers stays within the 64 register address space.
+///
+/// Returns [AdxlError::InvalidBusParams] for a range past the last register.
+///
+/// [AdxlError::InvalidBusParams]: enum.AdxlError.html#variant.InvalidBusParams
+pub(crate) fn check_range(start: u8, len: usize) -> Result {
+    if start as usize + len > 0x40 {
+        return Err(AdxlError::InvalidBusParams);
+    }
+    Ok(())
+}
+
+/// Writes each of the given axis offset adjustments individually.
+///
+/// ## Arguments
+/// * `writer` - Device the offset registers are written to.
+/// * `x` - X-axis offset adjustment or `None` to leave it unchanged.
+/// * `y` - Y-axis offset adjustment or `None` to leave it unchanged.
+/// * `z` - Z-axis offset adjustment or `None` to leave it unchanged.
+pub(crate) fn write_offsets<W>(
+    writer: &mut W,
+    x: Option<i8>,
+    y: Option<i8>,
+    z: Option<i8>,
+) -> Result
+where
+    W: Adxl345Writer + ?Sized,
+{
+    if let Some(x) = x {
+        writer.set_x_offset(x)?
+    };
+    if let Some(y) = y {
+        writer.set_y_offset(y)?
+    };
+    if let Some(z) = z {
+        writer.set_z_offset(z)?
+    };
+    Ok(())
+}
+
+/// Logs a warning naming the register and any reserved bits set in the value
+/// read from it, for the lossy readers which drop them.
+///
+/// ## Arguments
+/// * `register` - Address (offset) of the register read.
+/// * `value` - Byte read from the register.
+/// * `mask` - Bits of the register which are defined.
+#[cfg_attr(not(feature = "log"), allow(unused_variables))]
+fn warn_stray_bits(register: u8, value: u8, mask: u8) {
+    let stray = value & !mask;
+    if stray != 0 {
+        log_warn!(
+            "Ignoring reserved bits {:#04x} read from {} ({:#04x})",
+            stray,
+            crate::logging::register_name(register),
+            register
+        );
+    }
+}
+
+/// Checks if the register is one of the writable (non-reserved) registers.
+fn is_writable(register: u8) -> bool {
+    matches!(register, 0x1d..=0x2a | 0x2c..=0x2f | 0x31 | 0x38)
+}
+
+/// Reads back a just written register and compares it with the written byte.
+///
+/// Read-only and reserved registers are skipped.
+pub(crate) fn verify_register<R>(reader: &R, register: u8, byte: u8) -> Result
+where
+    R: Adxl345Reader + ?Sized,
+{
+    if !is_writable(register) {
+        return Ok(());
+    }
+    let read = reader.access(register)?;
+    if read == byte {
+        Ok(())
+    } else {
+        Err(AdxlError::VerifyMismatch {
+            register,
+            wrote: byte,
+            read,
+        })
+    }
+}
 
 /// Complete R/W register command set for the accelerometer.
-pub trait Adxl345: Adxl345Reader + Adxl345Writer {}
+pub trait Adxl345: Adxl345Reader + Adxl345Writer {
+    /// Sends a command and then reads the register back to confirm the byte
+    /// took effect.
+    ///
+    /// Read-back is skipped for read-only and reserved registers.
+    ///
+    /// ___Note:___ _This doubles the bus traffic of each write._
+    ///
+    /// ## Arguments
+    /// * `register` - Register address to be written.
+    /// * `byte` - Byte of data to be written into the given register.
+    fn command_verified(&mut self, register: u8, byte: u8) -> Result {
+        self.command(register, byte)?;
+        verify_register(self, register, byte)
+    }
+    /// Configures linked activity/inactivity detection with auto-sleep and
+    /// enables measurement mode.
+    ///
+    /// The device is placed into standby while the thresholds, inactivity
+    /// time, and activity/inactivity control are written and then the `link`,
+    /// `auto_sleep`, and `measure` bits are set together.
+    /// The current `wakeup` frequency is kept.
+    ///
+    /// Returns [AdxlError::HazardousConfig] without writing anything if the
+    /// link chain can't be satisfied because a threshold is 0 or no activity
+    /// or inactivity axis is enabled.
+    ///
+    /// ## Arguments
+    /// * `act_thresh` - Threshold value for detecting activity.
+    ///   The scale factor is 62.5 mg/LSB.
+    /// * `inact_thresh` - Threshold value for detecting inactivity.
+    ///   The scale factor is 62.5 mg/LSB.
+    /// * `inact_time_s` - Time in seconds acceleration must be below
+    ///   `inact_thresh` for inactivity to be declared and the part to sleep.
+    /// * `axes` - Activity/inactivity axis enable and coupling flags.
+    ///
+    /// [AdxlError::HazardousConfig]: enum.AdxlError.html#variant.HazardousConfig
+    fn configure_auto_sleep(
+        &mut self,
+        act_thresh: u8,
+        inact_thresh: u8,
+        inact_time_s: u8,
+        axes: ActivityMode,
+    ) -> Result {
+        let act_axes =
+            ActivityMode::ACT_X_ENABLE | ActivityMode::ACT_Y_ENABLE | ActivityMode::ACT_Z_ENABLE;
+        let inact_axes = ActivityMode::INACT_X_ENABLE
+            | ActivityMode::INACT_Y_ENABLE
+            | ActivityMode::INACT_Z_ENABLE;
+        if act_thresh == 0 || inact_thresh == 0 {
+            return Err(AdxlError::HazardousConfig(
+                "auto-sleep requires non-zero activity and inactivity thresholds",
+            ));
+        }
+        if !axes.intersects(act_axes) || !axes.intersects(inact_axes) {
+            return Err(AdxlError::HazardousConfig(
+                "auto-sleep requires at least one activity and one inactivity axis",
+            ));
+        }
+        let mut power = self.power_control_lossy()?;
+        power.set_measure(false);
+        self.set_power_control(power)?;
+        self.set_activity_threshold(act_thresh)?;
+        self.set_inactivity(inact_thresh, inact_time_s)?;
+        self.set_activity_control(axes)?;
+        power.set_sleep(false);
+        power.set_link(true);
+        power.set_auto_sleep(true);
+        power.set_measure(true);
+        self.set_power_control(power)
+    }
+    /// Enables exactly the listed interrupts on their chosen pins.
+    ///
+    /// Builds the interrupt map and enable registers from the list and writes
+    /// them with [route_interrupts()] so every interrupt not listed is
+    /// disabled.
+    ///
+    /// Returns [AdxlError::HazardousConfig] without writing anything if the
+    /// same interrupt is listed on both pins.
+    ///
+    /// ```ignore
+    /// adxl345.configure_interrupts(&[
+    ///     (IntSourceKind::FreeFall, IntPin::Int1),
+    ///     (IntSourceKind::DoubleTap, IntPin::Int2),
+    /// ])?;
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `interrupts` - Interrupts to be enabled and the pin of each.
+    ///
+    /// [route_interrupts()]: trait.Adxl345.html#method.route_interrupts
+    /// [AdxlError::HazardousConfig]: enum.AdxlError.html#variant.HazardousConfig
+    fn configure_interrupts(&mut self, interrupts: &[(IntSourceKind, IntPin)]) -> Result {
+        let mut int1 = IntControlMode::empty();
+        let mut int2 = IntControlMode::empty();
+        for (kind, pin) in interrupts {
+            match pin {
+                IntPin::Int1 => int1 |= kind.control_flag(),
+                IntPin::Int2 => int2 |= kind.control_flag(),
+            }
+        }
+        self.route_interrupts(int1, int2)
+    }
+    /// Turns off activity and inactivity detection.
+    ///
+    /// The ACTIVITY and INACTIVITY interrupts are disabled first and then all
+    /// the activity and inactivity axis enable bits are cleared.
+    /// The AC/DC coupling bits are kept.
+    fn disable_all_activity(&mut self) -> Result {
+        let mut interrupts = self.interrupt_control()?;
+        interrupts.remove(IntControlMode::ACTIVITY_ENABLE | IntControlMode::INACTIVITY_ENABLE);
+        self.set_interrupt_control(interrupts)?;
+        let coupling = ActivityMode::ACT_AC | ActivityMode::INACT_AC;
+        let mode = self.activity_control()? & coupling;
+        self.set_activity_control(mode)
+    }
+    /// Turns off single and double tap detection.
+    ///
+    /// The SINGLE_TAP and DOUBLE_TAP interrupts are disabled first and then all
+    /// the tap axis enable bits are cleared.
+    /// The double tap suppress bit is kept.
+    fn disable_all_taps(&mut self) -> Result {
+        let mut interrupts = self.interrupt_control()?;
+        interrupts.remove(IntControlMode::SINGLE_TAP_ENABLE | IntControlMode::DOUBLE_TAP_ENABLE);
+        self.set_interrupt_control(interrupts)?;
+        let mode = self.tap_control()? & TapMode::DT_DISABLE;
+        self.set_tap_control(mode)
+    }
+    /// Turns on activity and inactivity detection for all axes.
+    ///
+    /// All the activity and inactivity axis enable bits are set, keeping the
+    /// AC/DC coupling bits, and then the ACTIVITY and INACTIVITY interrupts are
+    /// enabled using [set_interrupt_control_checked()].
+    ///
+    /// [set_interrupt_control_checked()]: trait.Adxl345.html#method.set_interrupt_control_checked
+    fn enable_all_activity(&mut self) -> Result {
+        let axes = ActivityMode::ACT_X_ENABLE
+            | ActivityMode::ACT_Y_ENABLE
+            | ActivityMode::ACT_Z_ENABLE
+            | ActivityMode::INACT_X_ENABLE
+            | ActivityMode::INACT_Y_ENABLE
+            | ActivityMode::INACT_Z_ENABLE;
+        let mode = self.activity_control()? | axes;
+        self.set_activity_control(mode)?;
+        let interrupts = self.interrupt_control()?
+            | IntControlMode::ACTIVITY_ENABLE
+            | IntControlMode::INACTIVITY_ENABLE;
+        self.set_interrupt_control_checked(interrupts)
+    }
+    /// Turns on single and double tap detection for all axes.
+    ///
+    /// All the tap axis enable bits are set, keeping the double tap suppress
+    /// bit, and then the SINGLE_TAP and DOUBLE_TAP interrupts are enabled using
+    /// [set_interrupt_control_checked()].
+    ///
+    /// [set_interrupt_control_checked()]: trait.Adxl345.html#method.set_interrupt_control_checked
+    fn enable_all_taps(&mut self) -> Result {
+        let axes = TapMode::X_ENABLE | TapMode::Y_ENABLE | TapMode::Z_ENABLE;
+        let mode = self.tap_control()? | axes;
+        self.set_tap_control(mode)?;
+        let interrupts = self.interrupt_control()?
+            | IntControlMode::SINGLE_TAP_ENABLE
+            | IntControlMode::DOUBLE_TAP_ENABLE;
+        self.set_interrupt_control_checked(interrupts)
+    }
+    /// Reads the samples retained by the fifo after a trigger event and then
+    /// re-arms the trigger.
+    ///
+    /// Returns an empty list without touching the fifo when the `fifo_trigger`
+    /// bit of [FifoStatus] is not set.
+    ///
+    /// Only the `samples` entries retained from before the trigger event are
+    /// read, or fewer if the fifo holds fewer entries. Any entries collected
+    /// after the event are dropped with the fifo by the re-arm.
+    ///
+    /// Once triggered the device ignores further trigger events until trigger
+    /// mode is reset, so after draining the retained entries the following
+    /// documented sequence is used:
+    ///
+    /// 1. Read the retained entries, as the next step clears the fifo.
+    /// 2. Write FIFO_CTL with bypass mode.
+    /// 3. Write FIFO_CTL back with the original trigger mode value.
+    ///
+    /// Skipping the bypass write leaves the trigger latched.
+    ///
+    /// The original FIFO_CTL value is checked before the bypass write so an
+    /// [AdxlError::InvalidFifoConfig] is returned with the fifo untouched
+    /// instead of the re-arm failing after the fifo has been cleared.
+    ///
+    /// [FifoStatus]: struct.FifoStatus.html
+    /// [AdxlError::InvalidFifoConfig]: enum.AdxlError.html#variant.InvalidFifoConfig
+    fn read_trigger_buffer(&mut self) -> AdxlResult<Vec<(i16, i16, i16)>> {
+        let status = self.fifo_status_lossy()?;
+        if !status.fifo_trigger() {
+            return Ok(Vec::new());
+        }
+        let fifo = self.fifo_control()?;
+        fifo.validate()?;
+        let retained = status.entries().min(fifo.samples());
+        let mut samples = vec![(0, 0, 0); retained as usize];
+        let count = self.read_fifo_into(&mut samples)?;
+        samples.truncate(count);
+        self.set_fifo_control(FifoControl::new_bypass())?;
+        self.set_fifo_control(fifo)?;
+        Ok(samples)
+    }
+    /// Routes interrupts to the INT1 and INT2 pins and enables them.
+    ///
+    /// The interrupt mapping is derived from the two sets where each source in
+    /// `int2` is mapped to the INT2 pin and everything else to INT1, then the
+    /// union of the two sets is enabled.
+    /// The mapping is written before the interrupts are enabled so no event is
+    /// signaled on the wrong pin.
+    ///
+    /// Returns [AdxlError::HazardousConfig] without writing anything if the
+    /// same source is requested on both pins.
+    ///
+    /// For example, data ready on INT1 and single tap on INT2:
+    ///
+    /// ```ignore
+    /// adxl345.route_interrupts(
+    ///     IntControlMode::DATA_READY_ENABLE,
+    ///     IntControlMode::SINGLE_TAP_ENABLE,
+    /// )?;
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `int1` - Interrupts to be signaled on the INT1 pin.
+    /// * `int2` - Interrupts to be signaled on the INT2 pin.
+    ///
+    /// [AdxlError::HazardousConfig]: enum.AdxlError.html#variant.HazardousConfig
+    fn route_interrupts(&mut self, int1: IntControlMode, int2: IntControlMode) -> Result {
+        if int1.intersects(int2) {
+            return Err(AdxlError::HazardousConfig(
+                "interrupt source routed to both INT1 and INT2",
+            ));
+        }
+        self.set_interrupt_map(IntMapMode::from_bits_truncate(int2.bits()))?;
+        self.set_interrupt_control(int1 | int2)
+    }
+    /// Measures the raw shift of each axis caused by the self-test force.
+    ///
+    /// The `self_test` bit is cleared and then set, and after waiting the
+    /// [OutputDataRate::settling_time()] each time 10 samples, one per output
+    /// data period, are averaged.
+    /// The difference of the two averages, self-test on minus off, is
+    /// returned as right-justified counts in the current data format.
+    /// The original data format is restored afterwards even if a read fails.
+    ///
+    /// No pass or fail judgement is made so the result can be checked against
+    /// whatever limits the caller needs.
+    /// The datasheet limits depend on the supply voltage, range, and
+    /// resolution, with the Z-axis shift always positive and larger than the
+    /// X and Y-axis shifts.
+    ///
+    /// ___Note:___ _The device must be in measurement mode and kept still
+    /// while measuring. Each call takes about 20 output data periods plus two
+    /// settling times._
+    ///
+    /// [OutputDataRate::settling_time()]: enum.OutputDataRate.html#method.settling_time
+    fn self_test_delta(&mut self) -> AdxlResult<(i16, i16, i16)> {
+        const SAMPLES: i32 = 10;
+        let rate = self.data_rate()?;
+        let format = self.data_format_lossy()?;
+        let mut average = |self_test: bool| -> AdxlResult<(i32, i32, i32)> {
+            self.set_data_format(format.with_self_test(self_test))?;
+            sleep(rate.settling_time());
+            let mut sum = (0, 0, 0);
+            for _ in 0..SAMPLES {
+                let (x, y, z) = self.acceleration()?;
+                sum.0 += format.sign_extend_if_needed(x) as i32;
+                sum.1 += format.sign_extend_if_needed(y) as i32;
+                sum.2 += format.sign_extend_if_needed(z) as i32;
+                sleep(rate.period());
+            }
+            Ok((sum.0 / SAMPLES, sum.1 / SAMPLES, sum.2 / SAMPLES))
+        };
+        let delta = average(false).and_then(|off| {
+            let on = average(true)?;
+            Ok((
+                (on.0 - off.0) as i16,
+                (on.1 - off.1) as i16,
+                (on.2 - off.2) as i16,
+            ))
+        });
+        self.set_data_format(format)?;
+        delta
+    }
+    /// Set the output data rate.
+    ///
+    /// Only the `rate` bits of the data rate and power mode control register
+    /// are changed so the `low_power` bit is preserved.
+    ///
+    /// Returns [AdxlError::HazardousConfig] without writing anything if the
+    /// `low_power` bit is set and the new rate is outside the 12.5 Hz to
+    /// 400 Hz range where reduced power operation is supported.
+    ///
+    /// ## Arguments
+    /// * `rate` - New output data rate.
+    ///
+    /// [AdxlError::HazardousConfig]: enum.AdxlError.html#variant.HazardousConfig
+    fn set_data_rate(&mut self, rate: OutputDataRate) -> Result {
+        let mut bw_rate = self.bandwidth_rate_lossy()?;
+        if bw_rate.low_power() && !rate.supports_low_power() {
+            return Err(AdxlError::HazardousConfig(
+                "data rate not supported in low power mode",
+            ));
+        }
+        bw_rate.set_rate(rate as u8);
+        self.set_bandwidth_rate(bw_rate)
+    }
+    /// Set the output data rate and wait for the device to settle.
+    ///
+    /// The rate is changed the same way as [set_data_rate()] so the
+    /// `low_power` bit is preserved.
+    ///
+    /// After the write the thread sleeps for [OutputDataRate::settling_time()]
+    /// so the next acceleration read returns valid data.
+    ///
+    /// ## Arguments
+    /// * `rate` - New output data rate.
+    ///
+    /// See [set_data_rate_and_settle_extra()] when the analog front end needs
+    /// longer than the datasheet settling time.
+    ///
+    /// [OutputDataRate::settling_time()]: enum.OutputDataRate.html#method.settling_time
+    /// [set_data_rate()]: trait.Adxl345.html#method.set_data_rate
+    /// [set_data_rate_and_settle_extra()]: trait.Adxl345.html#method.set_data_rate_and_settle_extra
+    fn set_data_rate_and_settle(&mut self, rate: OutputDataRate) -> Result {
+        self.set_data_rate_and_settle_extra(rate, Duration::ZERO)
+    }
+    /// Set the output data rate and wait for the device to settle plus an
+    /// extra delay.
+    ///
+    /// Same as [set_data_rate_and_settle()] but `extra` is added to the
+    /// [OutputDataRate::settling_time()], for example when extra filtering
+    /// on the board slows down the response.
+    ///
+    /// ## Arguments
+    /// * `rate` - New output data rate.
+    /// * `extra` - Time added to the datasheet settling time.
+    ///
+    /// [OutputDataRate::settling_time()]: enum.OutputDataRate.html#method.settling_time
+    /// [set_data_rate_and_settle()]: trait.Adxl345.html#method.set_data_rate_and_settle
+    fn set_data_rate_and_settle_extra(&mut self, rate: OutputDataRate, extra: Duration) -> Result {
+        self.set_data_rate(rate)?;
+        sleep(rate.settling_time() + extra);
+        Ok(())
+    }
+    /// Set the active logic level of the interrupt pins.
+    ///
+    /// Only the `int_invert` bit of the data format register is changed so the
+    /// range, resolution, and other format bits are preserved.
+    ///
+    /// ## Arguments
+    /// * `level` - New active level of the interrupt pins.
+    fn set_interrupt_active_level(&mut self, level: ActiveLevel) -> Result {
+        let mut format = self.data_format_lossy()?;
+        format.set_int_invert(level == ActiveLevel::Low);
+        self.set_data_format(format)
+    }
+    /// Set interrupt control enable options after checking the matching
+    /// thresholds.
+    ///
+    /// The datasheet warns a threshold of 0 may result in undesirable behavior
+    /// when the matching interrupt is enabled so the threshold registers are
+    /// read first and [AdxlError::HazardousConfig] is returned without writing
+    /// anything if any enabled interrupt has a zero threshold:
+    ///
+    /// | Interrupt                  | Threshold register |
+    /// | -------------------------- | ------------------ |
+    /// | SINGLE_TAP and DOUBLE_TAP  | THRESH_TAP         |
+    /// | ACTIVITY                   | THRESH_ACT         |
+    /// | INACTIVITY                 | THRESH_INACT       |
+    /// | FREE_FALL                  | THRESH_FF          |
+    ///
+    /// Use [set_interrupt_control()] to write the mode unchecked.
+    ///
+    /// ## Arguments
+    /// * `mode` - Interrupt control mode bit flags.
+    ///   See [IntControlMode] bit flags for more info.
+    ///
+    /// [AdxlError::HazardousConfig]: enum.AdxlError.html#variant.HazardousConfig
+    /// [IntControlMode]: struct.IntControlMode.html
+    /// [set_interrupt_control()]: trait.Adxl345Writer.html#method.set_interrupt_control
+    fn set_interrupt_control_checked<IC>(&mut self, mode: IC) -> Result
+    where
+        IC: Into<IntControlMode>,
+    {
+        let mode = mode.into();
+        let tap = IntControlMode::SINGLE_TAP_ENABLE | IntControlMode::DOUBLE_TAP_ENABLE;
+        if mode.intersects(tap) && self.tap_threshold()? == 0 {
+            return Err(AdxlError::HazardousConfig(
+                "tap interrupt enabled with a zero tap threshold",
+            ));
+        }
+        if mode.contains(IntControlMode::ACTIVITY_ENABLE) && self.activity_threshold()? == 0 {
+            return Err(AdxlError::HazardousConfig(
+                "activity interrupt enabled with a zero activity threshold",
+            ));
+        }
+        if mode.contains(IntControlMode::INACTIVITY_ENABLE) && self.inactivity_threshold()? == 0 {
+            return Err(AdxlError::HazardousConfig(
+                "inactivity interrupt enabled with a zero inactivity threshold",
+            ));
+        }
+        if mode.contains(IntControlMode::FREE_FALL_ENABLE) && self.free_fall_threshold()? == 0 {
+            return Err(AdxlError::HazardousConfig(
+                "free-fall interrupt enabled with a zero free-fall threshold",
+            ));
+        }
+        self.set_interrupt_control(mode)
+    }
+    /// Set the measurement range.
+    ///
+    /// Only the `range` bits of the data format register are changed so the
+    /// resolution, justify, self-test, and other format bits are preserved.
+    ///
+    /// ## Arguments
+    /// * `range` - New measurement range.
+    fn set_range(&mut self, range: Range) -> Result {
+        let mut format = self.data_format_lossy()?;
+        format.set_range(range as u8);
+        self.set_data_format(format)
+    }
+    /// Set the fifo watermark level while keeping the current fifo mode and
+    /// trigger bits.
+    ///
+    /// Returns [AdxlError::InvalidFifoConfig] without writing anything if the
+    /// level is above 31 or the fifo is in bypass mode where a watermark has
+    /// no meaning.
+    ///
+    /// ## Arguments
+    /// * `level` - Number of fifo entries (0-31) needed before the `watermark`
+    ///   interrupt triggers.
+    ///   In trigger mode this is instead the number of entries retained before
+    ///   the trigger event and must not be 0.
+    ///
+    /// [AdxlError::InvalidFifoConfig]: enum.AdxlError.html#variant.InvalidFifoConfig
+    fn set_watermark(&mut self, level: u8) -> Result {
+        let mut fifo = self.fifo_control()?;
+        if level > FifoControl::MAX_SAMPLES || fifo.fifo_mode() == 0b00 {
+            return Err(AdxlError::InvalidFifoConfig(level));
+        }
+        fifo.set_samples(level);
+        self.set_fifo_control(fifo)
+    }
+    /// Wakes the device for a single sample and returns it to standby mode.
+    ///
+    /// The `measure` bit is set, the thread sleeps for the
+    /// [OutputDataRate::settling_time()] of the current data rate, one sample
+    /// is read, and the `measure` bit is cleared again even if the read fails.
+    ///
+    /// Each call takes about 1.1 ms plus one output data period plus the bus
+    /// time of five register accesses, for example about 11.1 ms at 100 Hz or
+    /// 1.4 ms at 3200 Hz, so use a fast data rate to keep the duty cycle low.
+    ///
+    /// See [single_shot_extra()] when the analog front end needs longer than
+    /// the datasheet settling time.
+    ///
+    /// [OutputDataRate::settling_time()]: enum.OutputDataRate.html#method.settling_time
+    /// [single_shot_extra()]: trait.Adxl345.html#method.single_shot_extra
+    fn single_shot(&mut self) -> AdxlResult<(i16, i16, i16)> {
+        self.single_shot_extra(Duration::ZERO)
+    }
+    /// Wakes the device for a single sample after the settling time plus an
+    /// extra delay and returns it to standby mode.
+    ///
+    /// Same as [single_shot()] but `extra` is added to the
+    /// [OutputDataRate::settling_time()] which also lengthens each call by
+    /// `extra`.
+    ///
+    /// ## Arguments
+    /// * `extra` - Time added to the datasheet settling time.
+    ///
+    /// [OutputDataRate::settling_time()]: enum.OutputDataRate.html#method.settling_time
+    /// [single_shot()]: trait.Adxl345.html#method.single_shot
+    fn single_shot_extra(&mut self, extra: Duration) -> AdxlResult<(i16, i16, i16)> {
+        let rate = self.data_rate()?;
+        let mut power = self.power_control_lossy()?;
+        power.set_measure(true);
+        self.set_power_control(power)?;
+        sleep(rate.settling_time() + extra);
+        let sample = self.acceleration();
+        power.set_measure(false);
+        self.set_power_control(power)?;
+        sample
+    }
+    /// Runs a set of configuration changes with the device in standby mode.
+    ///
+    /// The datasheet recommends changing settings like the data rate and power
+    /// bits while in standby to avoid glitched samples.
+    /// The `measure` bit is cleared, `f` is run, and then the `measure` bit is
+    /// restored to its original state on top of whatever power control value
+    /// `f` left behind.
+    ///
+    /// The `measure` bit is restored even when `f` fails, in which case the
+    /// error from `f` is returned.
+    ///
+    /// ## Arguments
+    /// * `f` - Closure doing the register writes.
+    fn with_standby<F>(&mut self, f: F) -> Result
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Result,
+    {
+        let mut power = self.power_control_lossy()?;
+        let measure = power.measure();
+        power.set_measure(false);
+        self.set_power_control(power)?;
+        let result = f(self);
+        let mut power = self.power_control_lossy()?;
+        power.set_measure(measure);
+        let restored = self.set_power_control(power);
+        result.and(restored)
+    }
+}
 
 /// Read register command set for accelerometer.
 pub trait Adxl345Reader {
@@ -84,20 +819,620 @@ pub trait Adxl345Reader {
     ///
     fn access(&self, register: u8) -> AdxlResult<u8>;
     /// Access the 3-axis of acceleration data together.
+    ///
+    /// The values are the raw little-endian register pairs where the lower
+    /// address register (DATAX0, DATAY0, DATAZ0) holds the least significant
+    /// byte, for example DATAX0 = `0xff` and DATAX1 = `0x01` is `0x01ff` or 511
+    /// counts in right-justified mode.
+    /// The byte order is fixed by the device so the host byte order does not
+    /// matter.
+    /// How many bits are significant depends on the current [DataFormat]
+    /// (see [DataFormat::resolution_bits()]) and in left-justified mode the
+    /// significant bits start at the MSB.
+    /// Use [DataFormat::sign_extend_if_needed()] to get comparable values in
+    /// all modes.
+    ///
+    /// [DataFormat]: struct.DataFormat.html
+    /// [DataFormat::resolution_bits()]: struct.DataFormat.html#method.resolution_bits
+    /// [DataFormat::sign_extend_if_needed()]: struct.DataFormat.html#method.sign_extend_if_needed
     fn acceleration(&self) -> AdxlResult<(i16, i16, i16)>;
+    /// Provides access to a contiguous range of register values.
+    ///
+    /// This is __NOT__ part of the actual ADXL345 command register set but
+    /// underpins FIFO drains and register dumps.
+    ///
+    /// The default implementation reads each register individually so drivers
+    /// should override it with a single multi-byte bus transaction.
+    ///
+    /// ## Arguments
+    /// * `start` - First register address to be accessed (read).
+    /// * `len` - Number of registers to read.
+    ///   Returns [AdxlError::InvalidBusParams] if the range goes past the
+    ///   last register (0x3f).
+    ///
+    /// [AdxlError::InvalidBusParams]: enum.AdxlError.html#variant.InvalidBusParams
+    fn access_range(&self, start: u8, len: usize) -> AdxlResult<Vec<u8>> {
+        check_range(start, len)?;
+        (0..len).map(|i| self.access(start + i as u8)).collect()
+    }
     //
     // ## Shouldn't be a need to change these methods in driver implementations. ##
     //
     // ### Convenience methods which allow accessing registers in related sets.
     //
+    /// Access the 3-axis of acceleration data together scaled to g.
+    ///
+    /// The current [DataFormat] is read to find the scale factor.
+    ///
+    /// [DataFormat]: struct.DataFormat.html
+    fn acceleration_g(&self) -> AdxlResult<(f64, f64, f64)> {
+        let format = self.data_format()?;
+        Ok(sample_to_g(self.acceleration()?, format))
+    }
+    /// Access the 3-axis of acceleration data together as integer milli-g.
+    ///
+    /// Uses only integer math for targets without floating point support.
+    /// The current [DataFormat] is read and each count is multiplied by an
+    /// exact integer scale of `1000 / 256` times the range multiplier:
+    ///
+    /// | Range | Normal (mg/LSB) | Full resolution (mg/LSB) |
+    /// | ----: | --------------: | -----------------------: |
+    /// |  ±2 g |  1000 / 256     |  1000 / 256              |
+    /// |  ±4 g |  2000 / 256     |  1000 / 256              |
+    /// |  ±8 g |  4000 / 256     |  1000 / 256              |
+    /// | ±16 g |  8000 / 256     |  1000 / 256              |
+    ///
+    /// The result is truncated toward zero.
+    ///
+    /// [DataFormat]: struct.DataFormat.html
+    fn acceleration_mg(&self) -> AdxlResult<(i32, i32, i32)> {
+        let format = self.data_format()?;
+        let scale = if format.full_res() {
+            1000
+        } else {
+            1000 << format.range()
+        };
+        let (x, y, z) = self.acceleration()?;
+        let to_mg = |counts: i16| format.sign_extend_if_needed(counts) as i32 * scale / 256;
+        Ok((to_mg(x), to_mg(y), to_mg(z)))
+    }
+    /// Access the 3-axis of acceleration data together as typed raw counts.
+    ///
+    /// The returned [Counts] can only be turned into physical units with an
+    /// explicit conversion using the matching [DataFormat].
+    ///
+    /// [Counts]: struct.Counts.html
+    /// [DataFormat]: struct.DataFormat.html
+    fn acceleration_typed(&self) -> AdxlResult<(Counts, Counts, Counts)> {
+        let (x, y, z) = self.acceleration()?;
+        Ok((Counts(x), Counts(y), Counts(z)))
+    }
+    /// Access which axes were involved in the last activity event.
+    ///
+    /// Only the `ACT_X`, `ACT_Y`, and `ACT_Z` bits of the activity/tap status
+    /// register are decoded.
+    ///
+    /// ___Note:___ _The bits are not cleared but overwritten by the next
+    /// activity event._
+    fn active_axes(&self) -> AdxlResult<(bool, bool, bool)> {
+        let register = 0x2b;
+        let status = self.access(register)?;
+        Ok((
+            status & ATStatus::ACT_X.bits() != 0,
+            status & ATStatus::ACT_Y.bits() != 0,
+            status & ATStatus::ACT_Z.bits() != 0,
+        ))
+    }
+    /// Access the current output bandwidth in Hz.
+    ///
+    /// The bandwidth is half of the output data rate.
+    fn bandwidth_hz(&self) -> AdxlResult<f64> {
+        Ok(self.data_rate()?.bandwidth_hz())
+    }
+    /// Access the current output data rate decoded from the rate code.
+    fn data_rate(&self) -> AdxlResult<OutputDataRate> {
+        OutputDataRate::try_from(self.bandwidth_rate()?.rate())
+    }
+    /// Access the current output data rate in Hz.
+    fn data_rate_hz(&self) -> AdxlResult<f64> {
+        Ok(self.data_rate()?.hz())
+    }
+    /// Access the current device configuration as a profile which can be
+    /// saved and later reloaded.
+    ///
+    /// Reads the configuration with [read_configuration()] for snapshotting a
+    /// tuned device.
+    /// With the `serde` feature enabled the returned [Adxl345Config] can be
+    /// serialized directly to any serde supported format, like JSON or TOML,
+    /// and after deserializing written to the same or another device with
+    /// [Adxl345Config::apply()].
+    ///
+    /// Unlike [read_configuration()] the fifo control is normalised so the
+    /// profile can be applied: a `samples` value left set in bypass mode,
+    /// where the device ignores it, is cleared.
+    /// A device left in trigger mode with a `samples` value of 0 is returned
+    /// as read, and [Adxl345Config::apply()] rejects that profile before
+    /// writing anything, as does deserializing it.
+    ///
+    /// [read_configuration()]: trait.Adxl345Reader.html#method.read_configuration
+    /// [Adxl345Config]: struct.Adxl345Config.html
+    /// [Adxl345Config::apply()]: struct.Adxl345Config.html#method.apply
+    fn dump_profile(&self) -> AdxlResult<Adxl345Config> {
+        let mut config = self.read_configuration()?;
+        if config.fifo_control.fifo_mode() == 0b00 {
+            config.fifo_control.set_samples(0);
+        }
+        Ok(config)
+    }
+    /// Estimates the nominal supply current in μA for the current data rate
+    /// and power settings.
+    ///
+    /// Uses the typical values from the datasheet current consumption tables
+    /// at a 2.5 V supply:
+    ///
+    /// * Standby mode reports 0 as the 0.1 μA typical rounds down.
+    /// * Sleep mode uses the data rate nearest below the `wakeup` frequency
+    ///   (8 Hz uses 6.25 Hz, 4 Hz uses 3.13 Hz, 2 Hz uses 1.56 Hz, and 1 Hz
+    ///   uses 0.78 Hz) as an approximation.
+    /// * Otherwise see [OutputDataRate::supply_current_ua()].
+    ///
+    /// [OutputDataRate::supply_current_ua()]: enum.OutputDataRate.html#method.supply_current_ua
+    fn estimated_current_ua(&self) -> AdxlResult<u32> {
+        let power = self.power_control_lossy()?;
+        if !power.measure() {
+            return Ok(0);
+        }
+        if power.sleep() {
+            let rate = match power.wakeup() {
+                0b00 => OutputDataRate::Hz6_25,
+                0b01 => OutputDataRate::Hz3_13,
+                0b10 => OutputDataRate::Hz1_56,
+                _ => OutputDataRate::Hz0_78,
+            };
+            return Ok(rate.supply_current_ua(false));
+        }
+        let bw_rate = self.bandwidth_rate_lossy()?;
+        let rate = OutputDataRate::try_from(bw_rate.rate())?;
+        Ok(rate.supply_current_ua(bw_rate.low_power()))
+    }
+    /// Access the interrupt source limited to the events whose interrupts are
+    /// currently enabled.
+    ///
+    /// The DATA_READY, WATERMARK, and OVERRUN functions are always running so
+    /// their bits are set in the interrupt source even when their interrupts
+    /// are disabled, unlike the other events which are only seen when enabled.
+    /// Masking the source with the interrupt enable register leaves only the
+    /// events the caller actually asked for.
+    ///
+    /// ___Note:___ _Reading the interrupt source clears the latched tap,
+    /// activity, inactivity, and free-fall bits._
+    fn fired_events(&self) -> AdxlResult<IntSource> {
+        let enabled = self.interrupt_control()?.bits();
+        let source = self.interrupt_source()?;
+        Ok(IntSource::from_bits_truncate(source.bits() & enabled))
+    }
     /// Access the current free-fall threshold and time values.
     fn free_fall(&self) -> AdxlResult<(u8, u8)> {
         Ok((self.free_fall_threshold()?, self.free_fall_time()?))
     }
+    /// Access the largest positive count for the current data format.
+    ///
+    /// The count is right-justified, as returned by
+    /// [DataFormat::sign_extend_if_needed()], and depends on the resolution
+    /// so it is 511 in 10-bit mode and grows to 4095 in full resolution at
+    /// ±16 g.
+    /// A reading at or beyond ± this count has likely been clipped.
+    ///
+    /// [DataFormat::sign_extend_if_needed()]: struct.DataFormat.html#method.sign_extend_if_needed
+    fn full_scale_counts(&self) -> AdxlResult<i16> {
+        let bits = self.data_format()?.resolution_bits();
+        Ok((1i16 << (bits - 1)) - 1)
+    }
+    /// Access the full scale of the current measurement range in g.
+    ///
+    /// Returns 2.0, 4.0, 8.0, or 16.0 for the ±2 g to ±16 g ranges.
+    fn full_scale_g(&self) -> AdxlResult<f64> {
+        Ok(self.range()?.g())
+    }
+    /// Access the tilt of the device from horizontal in degrees.
+    ///
+    /// This is the angle between the Z-axis and the gravity vector so it is 0°
+    /// when the device lies flat face up, 90° when on its side, and 180° when
+    /// upside down.
+    ///
+    /// ___Note:___ _The device is assumed to be roughly static so the measured
+    /// acceleration is only gravity. Any motion adds error._
+    fn inclination_deg(&self) -> AdxlResult<f64> {
+        let (x, y, z) = self.acceleration_g()?;
+        Ok((x * x + y * y).sqrt().atan2(z).to_degrees())
+    }
+    /// Access the angle of each axis from the horizontal plane in degrees.
+    ///
+    /// Each angle is between -90° and 90° with 0° meaning the axis is
+    /// horizontal and ±90° meaning it points straight up or down.
+    ///
+    /// ___Note:___ _The device is assumed to be roughly static so the measured
+    /// acceleration is only gravity. Any motion adds error._
+    fn inclination_per_axis(&self) -> AdxlResult<(f64, f64, f64)> {
+        let (x, y, z) = self.acceleration_g()?;
+        Ok((
+            axis_angle_deg(x, y, z),
+            axis_angle_deg(y, x, z),
+            axis_angle_deg(z, x, y),
+        ))
+    }
+    /// Access the pitch and roll of the device in centidegrees using only
+    /// integer math.
+    ///
+    /// Pitch is the angle of the X-axis and roll the angle of the Y-axis from
+    /// the horizontal plane, the same as the first two angles of
+    /// [inclination_per_axis()], each between -9000 and 9000 (±90°).
+    ///
+    /// Intended for targets without floating point support.
+    /// The arctangent is approximated to within about 0.25° and the raw counts
+    /// are used directly so at the coarsest (10-bit ±16 g) format the count
+    /// quantization adds up to a few degrees more near ±90°.
+    /// Use [inclination_per_axis()] where floating point is available.
+    ///
+    /// ___Note:___ _The device is assumed to be roughly static so the measured
+    /// acceleration is only gravity. Any motion adds error._
+    ///
+    /// [inclination_per_axis()]: trait.Adxl345Reader.html#method.inclination_per_axis
+    fn tilt_fixed(&self) -> AdxlResult<(i16, i16)> {
+        let format = self.data_format()?;
+        let (x, y, z) = self.acceleration()?;
+        let x = format.sign_extend_if_needed(x) as i64;
+        let y = format.sign_extend_if_needed(y) as i64;
+        let z = format.sign_extend_if_needed(z) as i64;
+        let angle = |a: i64, b: i64, c: i64| {
+            let angle = atan_centidegrees(a.unsigned_abs(), isqrt((b * b + c * c) as u64));
+            if a < 0 {
+                -angle
+            } else {
+                angle
+            }
+        };
+        Ok((angle(x, y, z), angle(y, x, z)))
+    }
     /// Access all 3-axis of the offset adjustments.
     fn offset_adjustment(&self) -> AdxlResult<(i8, i8, i8)> {
         Ok((self.x_offset()?, self.y_offset()?, self.z_offset()?))
     }
+    /// Access all 3-axis of the offset adjustments in g.
+    ///
+    /// Register counts are converted using the fixed 15.6 mg/LSB scale factor.
+    fn offset_adjustment_g(&self) -> AdxlResult<(f64, f64, f64)> {
+        let (x, y, z) = self.offset_adjustment()?;
+        Ok((
+            x as f64 * OFFSET_SCALE_G,
+            y as f64 * OFFSET_SCALE_G,
+            z as f64 * OFFSET_SCALE_G,
+        ))
+    }
+    /// Access all 3-axis of the offset adjustments as the raw unsigned
+    /// register bytes.
+    ///
+    /// The three contiguous offset registers are read together with
+    /// [access_range()].
+    ///
+    /// [access_range()]: trait.Adxl345Reader.html#method.access_range
+    fn offset_raw(&self) -> AdxlResult<(u8, u8, u8)> {
+        let register = 0x1e;
+        let bytes = self.access_range(register, 3)?;
+        Ok((bytes[0], bytes[1], bytes[2]))
+    }
+    /// Averages acceleration samples taken at rest into a gravity unit vector.
+    ///
+    /// The device should be kept still in its mounted orientation while the
+    /// samples are read.
+    /// Samples are read back-to-back so `samples` should be large enough to
+    /// span several output data periods.
+    /// A zero vector is returned if no gravity was measured (free-fall).
+    ///
+    /// ## Arguments
+    /// * `samples` - Number of samples to average.
+    ///   A value of 0 is treated as 1.
+    fn learn_gravity(&self, samples: usize) -> AdxlResult<[f64; 3]> {
+        let format = self.data_format()?;
+        let scale = format.scale_factor();
+        let samples = samples.max(1);
+        let mut sum = [0f64; 3];
+        for _ in 0..samples {
+            let (x, y, z) = self.acceleration()?;
+            sum[0] += format.sign_extend_if_needed(x) as f64;
+            sum[1] += format.sign_extend_if_needed(y) as f64;
+            sum[2] += format.sign_extend_if_needed(z) as f64;
+        }
+        let gravity = sum.map(|axis| axis * scale / samples as f64);
+        let norm = gravity.iter().map(|axis| axis * axis).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return Ok(gravity);
+        }
+        Ok(gravity.map(|axis| axis / norm))
+    }
+    /// Access the current acceleration in g with gravity removed.
+    ///
+    /// ## Arguments
+    /// * `gravity` - Gravity unit vector as returned by [learn_gravity()].
+    ///   One g along this vector is subtracted from the current reading.
+    ///
+    /// [learn_gravity()]: trait.Adxl345Reader.html#method.learn_gravity
+    fn linear_acceleration(&self, gravity: &[f64; 3]) -> AdxlResult<(f64, f64, f64)> {
+        let (x, y, z) = self.acceleration_g()?;
+        Ok((x - gravity[0], y - gravity[1], z - gravity[2]))
+    }
+    /// Writes paced acceleration samples to `writer` as CSV until `running`
+    /// is cleared.
+    ///
+    /// A `timestamp,x,y,z` header row is written first followed by one row
+    /// per sample where the timestamp is the seconds since logging started.
+    /// The samples are paced the same as [samples_until()] and the writer is
+    /// flushed about once a second and again when logging stops.
+    ///
+    /// Any read or write error stops logging and is returned, with write
+    /// errors as [AdxlError::Io].
+    ///
+    /// ## Arguments
+    /// * `writer` - Destination of the CSV rows, for example a file or stdout.
+    /// * `period` - Target time between samples.
+    /// * `running` - Flag which keeps logging going while true.
+    /// * `units` - Units of the logged values, see [CsvUnits].
+    ///
+    /// [samples_until()]: trait.Adxl345Reader.html#method.samples_until
+    /// [AdxlError::Io]: enum.AdxlError.html#variant.Io
+    /// [CsvUnits]: enum.CsvUnits.html
+    fn log_csv<W: Write>(
+        &self,
+        writer: W,
+        period: Duration,
+        running: Arc<AtomicBool>,
+        units: CsvUnits,
+    ) -> Result {
+        csv::log_csv(self, writer, period, running, units)
+    }
+    /// Measures the RMS noise of each axis in g.
+    ///
+    /// Returns the standard deviation of `samples` readings, which is the RMS
+    /// of their deviation from the mean, so the device must be kept still
+    /// while they are read.
+    /// Samples are scaled the same as [acceleration_g()] with the data format
+    /// read once at the start.
+    ///
+    /// The noise depends on the output data rate since the bandwidth is half
+    /// of it, so faster rates let through more noise.
+    /// Samples are read back-to-back so at slow rates the same sample is seen
+    /// more than once which lowers the result.
+    /// Wait for data ready or keep `samples` well above the rate times the
+    /// read duration for a representative value.
+    ///
+    /// ## Arguments
+    /// * `samples` - Number of samples to measure.
+    ///   A value of 0 is treated as 1.
+    ///
+    /// [acceleration_g()]: trait.Adxl345Reader.html#method.acceleration_g
+    fn noise_rms(&self, samples: usize) -> AdxlResult<(f64, f64, f64)> {
+        let format = self.data_format()?;
+        let scale = format.scale_factor();
+        let samples = samples.max(1);
+        let mut sum = [0f64; 3];
+        let mut sum_sq = [0f64; 3];
+        for _ in 0..samples {
+            let (x, y, z) = self.acceleration()?;
+            for (i, raw) in [x, y, z].iter().enumerate() {
+                let g = format.sign_extend_if_needed(*raw) as f64 * scale;
+                sum[i] += g;
+                sum_sq[i] += g * g;
+            }
+        }
+        let n = samples as f64;
+        let rms = |i: usize| {
+            let mean = sum[i] / n;
+            (sum_sq[i] / n - mean * mean).max(0.0).sqrt()
+        };
+        Ok((rms(0), rms(1), rms(2)))
+    }
+    /// Calls `f` with each new acceleration sample.
+    ///
+    /// Loops polling the interrupt source for the DATA_READY bit, which is
+    /// always visible even when its interrupt is disabled, and sleeping for
+    /// `period` between polls.
+    /// Each time it is set one sample is read, which also clears the bit, and
+    /// passed to `f`.
+    ///
+    /// Only returns when there is an error accessing the device.
+    ///
+    /// ___Note:___ _Reading the interrupt source also clears any latched tap,
+    /// activity, inactivity, and free-fall events so they are not seen
+    /// elsewhere while this is running._
+    ///
+    /// ## Arguments
+    /// * `period` - Time to sleep between polls. Should be shorter than the
+    ///   output data rate period so no samples are skipped.
+    /// * `f` - Callback given each sample.
+    fn on_data_ready<F>(&self, period: Duration, mut f: F) -> Result
+    where
+        F: FnMut((i16, i16, i16)),
+        Self: Sized,
+    {
+        loop {
+            if self.interrupt_source()?.contains(IntSource::DATA_READY) {
+                f(self.acceleration()?);
+            }
+            sleep(period);
+        }
+    }
+    /// Drains up to `N` entries from the fifo into a stack array.
+    ///
+    /// Same as [read_fifo_into()] with the buffer sized at compile time and
+    /// returned together with the number of entries filled.
+    /// Entries past the count are left as `(0, 0, 0)`.
+    ///
+    /// ___Note:___ _The fifo holds up to 32 entries so `N` should be at least
+    /// 32 to drain a full fifo in one call._
+    ///
+    /// [read_fifo_into()]: trait.Adxl345Reader.html#method.read_fifo_into
+    #[allow(clippy::type_complexity)]
+    fn read_fifo_array<const N: usize>(&self) -> AdxlResult<([(i16, i16, i16); N], usize)>
+    where
+        Self: Sized,
+    {
+        let mut samples = [(0, 0, 0); N];
+        let count = self.read_fifo_into(&mut samples)?;
+        Ok((samples, count))
+    }
+    /// Drains entries from the fifo into a caller-supplied slice.
+    ///
+    /// Reads `min(entries, out.len())` entries without any allocation, where
+    /// `entries` is the fifo entry count at the start of the call, and leaves
+    /// any remaining entries in the fifo.
+    ///
+    /// Returns how many entries were read into the start of `out`.
+    ///
+    /// ___Note:___ _The datasheet requires at least 5 μs between reads of the
+    /// fifo which the per read bus overhead of the drivers already provides._
+    ///
+    /// ## Arguments
+    /// * `out` - Buffer the entries are read into.
+    fn read_fifo_into(&self, out: &mut [(i16, i16, i16)]) -> AdxlResult<usize> {
+        let count = out.len().min(self.fifo_entries()? as usize);
+        for entry in out.iter_mut().take(count) {
+            *entry = self.acceleration()?;
+        }
+        Ok(count)
+    }
+    /// Access all the configuration registers decoded together as a single
+    /// snapshot.
+    ///
+    /// Can be used with [Adxl345Config::apply()] for read-modify-apply
+    /// workflows or to log the complete decoded device state.
+    ///
+    /// [Adxl345Config::apply()]: struct.Adxl345Config.html#method.apply
+    fn read_configuration(&self) -> AdxlResult<Adxl345Config> {
+        let (free_fall_threshold, free_fall_time) = self.free_fall()?;
+        Ok(Adxl345Config {
+            activity_control: self.activity_control()?,
+            activity_threshold: self.activity_threshold()?,
+            bandwidth_rate: self.bandwidth_rate()?,
+            data_format: self.data_format()?,
+            fifo_control: self.fifo_control()?,
+            free_fall_threshold,
+            free_fall_time,
+            inactivity_threshold: self.inactivity_threshold()?,
+            inactivity_time: self.inactivity_time()?,
+            interrupt_control: self.interrupt_control()?,
+            interrupt_map: self.interrupt_map()?,
+            offset_adjustment: self.offset_adjustment()?,
+            power_control: self.power_control()?,
+            tap: self.tap()?,
+            tap_control: self.tap_control()?,
+        })
+    }
+    /// Access the latest acceleration sample together with the interrupt
+    /// source in a single bus transaction.
+    ///
+    /// INT_SOURCE (0x30) through DATAZ1 (0x37) are read with one
+    /// [access_range()] burst so no new event can slip in between the two
+    /// reads on drivers which override it with a multi-byte transaction.
+    /// The interrupt source is read first and so reflects the state at the
+    /// moment the returned sample was taken, for example DATA_READY being set
+    /// means the sample is new.
+    /// The data registers are read right after it which clears DATA_READY,
+    /// and OVERRUN in bypass mode, the same as [acceleration()].
+    ///
+    /// ___Note:___ _Reading the interrupt source clears the latched tap,
+    /// activity, inactivity, and free-fall bits._
+    ///
+    /// [acceleration()]: trait.Adxl345Reader.html#tymethod.acceleration
+    /// [access_range()]: trait.Adxl345Reader.html#method.access_range
+    #[allow(clippy::type_complexity)]
+    fn read_sample_and_source(&self) -> AdxlResult<((i16, i16, i16), IntSource)> {
+        let register = 0x30;
+        let block = self.access_range(register, 8)?;
+        let sample = <[u8; 6]>::try_from(block.get(2..).unwrap_or_default()).map_err(|_| {
+            AdxlError::InvalidBlockLength {
+                expected: 8,
+                actual: block.len(),
+            }
+        })?;
+        let source = IntSource::from_bits(block[0]).ok_or(AdxlError::UnknownModeBit(block[0]))?;
+        Ok((parse_sample(&sample), source))
+    }
+    /// Snapshot of the raw values of all the named registers.
+    ///
+    /// Take one dump before and one after a change and use
+    /// [RegisterDump::diff()] to see which registers it actually touched.
+    ///
+    /// ___Note:___ _The data registers and INT_SOURCE are included so taking a
+    /// dump has the same side effects as reading a sample and the interrupt
+    /// source._
+    ///
+    /// [RegisterDump::diff()]: struct.RegisterDump.html#method.diff
+    fn register_dump(&self) -> AdxlResult<RegisterDump> {
+        let device_id = self.device_id()?;
+        let block = self.access_range(Adxl345Config::BLOCK_START, Adxl345Config::BLOCK_LEN)?;
+        RegisterDump::new(device_id, &block)
+    }
+    /// Access the nominal time between samples at the current output data
+    /// rate.
+    ///
+    /// See [sample_period_us()] for an integer only variant.
+    ///
+    /// [sample_period_us()]: trait.Adxl345Reader.html#method.sample_period_us
+    fn sample_period(&self) -> AdxlResult<Duration> {
+        Ok(self.data_rate()?.period())
+    }
+    /// Access the nominal time between samples at the current output data
+    /// rate in whole μs.
+    ///
+    /// Uses only integer math for targets without floating point support.
+    /// The 312.5 μs period at 3200 Hz is truncated to 312 μs.
+    fn sample_period_us(&self) -> AdxlResult<u32> {
+        Ok(self.data_rate()?.period_us())
+    }
+    /// Stream of paced acceleration samples which stops once `running` is
+    /// cleared.
+    ///
+    /// Packages the usual Ctrl-C handling loop so only the flag needs to be
+    /// shared with the handler.
+    ///
+    /// ## Arguments
+    /// * `running` - Flag which keeps the stream going while true.
+    /// * `period` - Target time between samples.
+    ///
+    /// See [SamplesUntil] for more information.
+    ///
+    /// [SamplesUntil]: struct.SamplesUntil.html
+    fn samples_until(&self, running: Arc<AtomicBool>, period: Duration) -> SamplesUntil<'_, Self> {
+        SamplesUntil::new(self, running, period)
+    }
+    /// Reads the interrupt source and, when a data related interrupt is
+    /// pending, reads one acceleration sample to start clearing it.
+    ///
+    /// The DATA_READY, WATERMARK, and OVERRUN interrupts are only cleared by
+    /// reading the data registers:
+    ///
+    /// * DATA_READY is cleared by the single read unless more data is already
+    ///   waiting in the fifo.
+    /// * WATERMARK stays set until the fifo has been read down below the
+    ///   watermark which can require up to `samples` reads.
+    /// * OVERRUN is cleared by the single read in bypass mode but in the fifo
+    ///   modes up to 32 reads (a full fifo) may be needed.
+    ///
+    /// Call again until the returned [IntSource] no longer has the data bits
+    /// set when the fifo is in use.
+    ///
+    /// [IntSource]: struct.IntSource.html
+    #[allow(clippy::type_complexity)]
+    fn service_interrupts(&self) -> AdxlResult<(IntSource, Option<(i16, i16, i16)>)> {
+        let source = self.interrupt_source()?;
+        let data = IntSource::DATA_READY | IntSource::WATERMARK | IntSource::OVERRUN;
+        let sample = if source.intersects(data) {
+            Some(self.acceleration()?)
+        } else {
+            None
+        };
+        Ok((source, sample))
+    }
     /// Access to all non-control tap current values together as a structure.
     ///
     /// See [Tap] for more information.
@@ -112,6 +1447,101 @@ pub trait Adxl345Reader {
         ];
         Ok(values.into())
     }
+    /// Access which axes were involved in the last single or double tap
+    /// event.
+    ///
+    /// Only the `TAP_X`, `TAP_Y`, and `TAP_Z` bits of the activity/tap status
+    /// register are decoded.
+    ///
+    /// ___Note:___ _The bits are not cleared but overwritten by the next tap
+    /// event._
+    fn tap_axes(&self) -> AdxlResult<(bool, bool, bool)> {
+        let register = 0x2b;
+        let status = self.access(register)?;
+        Ok((
+            status & ATStatus::TAP_X.bits() != 0,
+            status & ATStatus::TAP_Y.bits() != 0,
+            status & ATStatus::TAP_Z.bits() != 0,
+        ))
+    }
+    /// Waits for a free-fall event.
+    ///
+    /// Polls the interrupt source every millisecond until the FREE_FALL bit is
+    /// set, returning `true`, or `timeout` elapses, returning `false`.
+    ///
+    /// ___Note:___ _The free-fall threshold and time must be set and the
+    /// FREE_FALL interrupt enabled before calling this or the event is never
+    /// seen. Reading the interrupt source also clears any other latched
+    /// events._
+    ///
+    /// ## Arguments
+    /// * `timeout` - Longest time to wait.
+    fn wait_for_free_fall(&self, timeout: Duration) -> AdxlResult<bool> {
+        let start = Instant::now();
+        loop {
+            if self.interrupt_source()?.contains(IntSource::FREE_FALL) {
+                return Ok(true);
+            }
+            if start.elapsed() >= timeout {
+                return Ok(false);
+            }
+            sleep(Duration::from_millis(1));
+        }
+    }
+    /// Waits for the device to wake from sleep mode.
+    ///
+    /// Polls the `ASLEEP` bit with [is_asleep()] every millisecond until it
+    /// clears or `timeout` elapses in which case [AdxlError::Timeout] is
+    /// returned.
+    ///
+    /// While asleep the device only samples at the `wakeup` frequency of the
+    /// power control register (8, 4, 2, or 1 Hz) so activity can take up to one
+    /// wakeup period (125 ms to 1 s) to be seen and wake the device.
+    /// A timeout shorter than that period may fail even with activity present.
+    ///
+    /// ## Arguments
+    /// * `timeout` - Longest time to wait.
+    ///
+    /// [is_asleep()]: trait.Adxl345Reader.html#method.is_asleep
+    /// [AdxlError::Timeout]: enum.AdxlError.html#variant.Timeout
+    fn wait_until_awake(&self, timeout: Duration) -> Result {
+        let start = Instant::now();
+        while self.is_asleep()? {
+            if start.elapsed() >= timeout {
+                return Err(AdxlError::Timeout(timeout));
+            }
+            sleep(Duration::from_millis(1));
+        }
+        Ok(())
+    }
+    /// Measures the zero-g offset of each axis in g.
+    ///
+    /// Averages `samples` readings and returns their deviation from the ideal
+    /// (0, 0, 1 g) of a device at rest with the Z-axis up.
+    /// Nothing is written to the offset registers so this can be used to
+    /// monitor drift over time in deployed systems.
+    ///
+    /// The device should be kept still, Z-axis up, while the samples are read.
+    /// Samples are read back-to-back so `samples` should be large enough to
+    /// span several output data periods.
+    ///
+    /// ## Arguments
+    /// * `samples` - Number of samples to average.
+    ///   A value of 0 is treated as 1.
+    fn zero_g_offset(&self, samples: usize) -> AdxlResult<(f64, f64, f64)> {
+        let format = self.data_format()?;
+        let scale = format.scale_factor();
+        let samples = samples.max(1);
+        let mut sum = [0f64; 3];
+        for _ in 0..samples {
+            let (x, y, z) = self.acceleration()?;
+            sum[0] += format.sign_extend_if_needed(x) as f64;
+            sum[1] += format.sign_extend_if_needed(y) as f64;
+            sum[2] += format.sign_extend_if_needed(z) as f64;
+        }
+        let [x, y, z] = sum.map(|axis| axis * scale / samples as f64);
+        Ok((x, y, z - 1.0))
+    }
     //
     // ### Per register access methods.
     //
@@ -145,12 +1575,46 @@ pub trait Adxl345Reader {
         let register = 0x2c;
         self.access(register)?.try_into()
     }
+    /// Access the current data rate and power mode control mode ignoring any
+    /// set reserved bits (bits 5-7).
+    ///
+    /// Useful with clone parts which leave reserved bits set where the strict
+    /// [bandwidth_rate()] would fail.
+    /// Any reserved bits found are logged as a warning when the `log` feature
+    /// is enabled.
+    ///
+    /// [bandwidth_rate()]: trait.Adxl345Reader.html#method.bandwidth_rate
+    fn bandwidth_rate_lossy(&self) -> AdxlResult<BandwidthRateControl> {
+        let register = 0x2c;
+        let value = self.access(register)?;
+        warn_stray_bits(register, value, BandwidthRateControl::MASK);
+        Ok(BandwidthRateControl::from_bits_truncate(value))
+    }
     /// Access the current data format mode.
     fn data_format(&self) -> AdxlResult<DataFormat> {
         let register = 0x31;
         self.access(register)?.try_into()
     }
+    /// Access the current data format mode ignoring any set reserved bit
+    /// (bit 4).
+    ///
+    /// Useful with clone parts which leave reserved bits set where the strict
+    /// [data_format()] would fail.
+    /// Any reserved bits found are logged as a warning when the `log` feature
+    /// is enabled.
+    ///
+    /// [data_format()]: trait.Adxl345Reader.html#method.data_format
+    fn data_format_lossy(&self) -> AdxlResult<DataFormat> {
+        let register = 0x31;
+        let value = self.access(register)?;
+        warn_stray_bits(register, value, DataFormat::MASK);
+        Ok(DataFormat::from_bits_truncate(value))
+    }
     /// Access the device ID.
+    ///
+    /// A genuine device always returns [DEVICE_ID].
+    ///
+    /// [DEVICE_ID]: constant.DEVICE_ID.html
     fn device_id(&self) -> AdxlResult<u8> {
         let register = 0x00;
         self.access(register)
@@ -170,10 +1634,59 @@ pub trait Adxl345Reader {
         let register = 0x38;
         Ok(self.access(register)?.into())
     }
-    /// Access the current fifo status.
-    fn fifo_status(&self) -> AdxlResult<FifoStatus> {
+    /// Access the current fifo mode decoded from the fifo control register.
+    ///
+    /// See [FifoMode] for more information.
+    ///
+    /// [FifoMode]: enum.FifoMode.html
+    fn fifo_mode(&self) -> AdxlResult<FifoMode> {
+        FifoMode::try_from(self.fifo_control()?.fifo_mode())
+    }
+    /// Access the current fifo status.
+    ///
+    /// Returns an error if the reserved bit (bit 6) is set, use
+    /// [fifo_status_lossy()] to ignore it or [fifo_status_raw()] for the
+    /// unparsed byte.
+    ///
+    /// [fifo_status_lossy()]: trait.Adxl345Reader.html#method.fifo_status_lossy
+    /// [fifo_status_raw()]: trait.Adxl345Reader.html#method.fifo_status_raw
+    fn fifo_status(&self) -> AdxlResult<FifoStatus> {
+        let register = 0x39;
+        self.access(register)?.try_into()
+    }
+    /// Access the current fifo status ignoring any set reserved bit (bit 6).
+    ///
+    /// Useful with clone parts which leave reserved bits set where the strict
+    /// [fifo_status()] would fail.
+    /// Any reserved bits found are logged as a warning when the `log` feature
+    /// is enabled.
+    ///
+    /// [fifo_status()]: trait.Adxl345Reader.html#method.fifo_status
+    fn fifo_status_lossy(&self) -> AdxlResult<FifoStatus> {
+        let register = 0x39;
+        let value = self.access(register)?;
+        warn_stray_bits(register, value, FifoStatus::MASK);
+        Ok(FifoStatus::from_bits_truncate(value))
+    }
+    /// Access the current fifo status as the unparsed register byte.
+    ///
+    /// Never fails on the value so the entry count (bits 0-5) can always be
+    /// read, even from non-compliant parts.
+    fn fifo_status_raw(&self) -> AdxlResult<u8> {
+        let register = 0x39;
+        self.access(register)
+    }
+    /// Access just the number of entries available in the fifo.
+    ///
+    /// Lighter weight alternative to [fifo_status()] intended for tight polling
+    /// loops as it only masks the `entries` bits and skips building the
+    /// [FifoStatus] structure.
+    ///
+    /// [fifo_status()]: trait.Adxl345Reader.html#method.fifo_status
+    /// [FifoStatus]: struct.FifoStatus.html
+    fn fifo_entries(&self) -> AdxlResult<u8> {
         let register = 0x39;
-        self.access(register)?.try_into()
+        Ok(self.access(register)? & 0x3f)
     }
     /// Access the current inactivity threshold value.
     fn inactivity_threshold(&self) -> AdxlResult<u8> {
@@ -192,6 +1705,17 @@ pub trait Adxl345Reader {
         let result = IntControlMode::from_bits(data).ok_or(AdxlError::UnknownModeBit(data))?;
         Ok(result)
     }
+    /// Access the current interrupt control mode as the unparsed register
+    /// byte.
+    ///
+    /// Useful to verify the register holds exactly what was written, for
+    /// example after [configure_interrupts()].
+    ///
+    /// [configure_interrupts()]: trait.Adxl345.html#method.configure_interrupts
+    fn interrupt_control_raw(&self) -> AdxlResult<u8> {
+        let register = 0x2e;
+        self.access(register)
+    }
     /// Access the current interrupt mapping mode.
     fn interrupt_map(&self) -> AdxlResult<IntMapMode> {
         let register = 0x2f;
@@ -199,6 +1723,17 @@ pub trait Adxl345Reader {
         let result = IntMapMode::from_bits(data).ok_or(AdxlError::UnknownModeBit(data))?;
         Ok(result)
     }
+    /// Access the current interrupt mapping mode as the unparsed register
+    /// byte.
+    ///
+    /// Useful to verify the register holds exactly what was written, for
+    /// example after [configure_interrupts()].
+    ///
+    /// [configure_interrupts()]: trait.Adxl345.html#method.configure_interrupts
+    fn interrupt_map_raw(&self) -> AdxlResult<u8> {
+        let register = 0x2f;
+        self.access(register)
+    }
     /// Access the current interrupt source.
     fn interrupt_source(&self) -> AdxlResult<IntSource> {
         let register = 0x30;
@@ -206,11 +1741,103 @@ pub trait Adxl345Reader {
         let result = IntSource::from_bits(data).ok_or(AdxlError::UnknownModeBit(data))?;
         Ok(result)
     }
+    /// Access if the part is currently asleep.
+    ///
+    /// Only the `ASLEEP` bit of the activity/tap status register is returned.
+    ///
+    /// ___Note:___ _This reads the same register as [activity_tap_status()] so
+    /// when the activity/tap source bits are also needed read them with that
+    /// method before clearing the interrupt instead._
+    ///
+    /// [activity_tap_status()]: trait.Adxl345Reader.html#method.activity_tap_status
+    fn is_asleep(&self) -> AdxlResult<bool> {
+        let register = 0x2b;
+        Ok(self.access(register)? & ATStatus::ASLEEP.bits() != 0)
+    }
+    /// Access if the part is in full resolution mode.
+    ///
+    /// Only the `full_res` bit (bit 3) of the data format register is checked
+    /// so the rest of the register does not need to be decoded.
+    fn is_full_resolution(&self) -> AdxlResult<bool> {
+        let register = 0x31;
+        Ok(self.access(register)? & 0x08 != 0)
+    }
+    /// Access if the data registers are left-justified (MSB mode).
+    ///
+    /// Only the `justify` bit (bit 2) of the data format register is checked
+    /// so the rest of the register does not need to be decoded.
+    fn is_left_justified(&self) -> AdxlResult<bool> {
+        let register = 0x31;
+        Ok(self.access(register)? & 0x04 != 0)
+    }
+    /// Access if the self-test force is being applied.
+    ///
+    /// Only the `self_test` bit (bit 7) of the data format register is checked.
+    /// Useful to assert it is off at startup as a forgotten self-test force
+    /// gives plausible but shifted readings.
+    fn is_self_test_active(&self) -> AdxlResult<bool> {
+        let register = 0x31;
+        Ok(self.access(register)? & 0x80 != 0)
+    }
+    /// Polls for a single or double tap event.
+    ///
+    /// The activity/tap status is read before the interrupt source, since
+    /// reading the interrupt source clears the tap event, so the returned axes
+    /// belong to the reported event.
+    ///
+    /// ___Note:___ _The SINGLE_TAP and/or DOUBLE_TAP interrupts must be
+    /// enabled for the events to be seen._
+    ///
+    /// Returns `None` when no tap event is pending.
+    fn poll_tap(&self) -> AdxlResult<Option<TapEvent>> {
+        let axes =
+            self.activity_tap_status()? & (ATStatus::TAP_X | ATStatus::TAP_Y | ATStatus::TAP_Z);
+        let source = self.interrupt_source()?;
+        Ok(if source.contains(IntSource::DOUBLE_TAP) {
+            Some(TapEvent::Double { axes })
+        } else if source.contains(IntSource::SINGLE_TAP) {
+            Some(TapEvent::Single { axes })
+        } else {
+            None
+        })
+    }
     /// Access the current power-saving features control mode.
     fn power_control(&self) -> AdxlResult<PowerControl> {
         let register = 0x2d;
         self.access(register)?.try_into()
     }
+    /// Access the current power-saving features control mode ignoring any set
+    /// reserved bits (bits 6-7).
+    ///
+    /// Useful with clone parts which leave reserved bits set where the strict
+    /// [power_control()] would fail.
+    /// Any reserved bits found are logged as a warning when the `log` feature
+    /// is enabled.
+    ///
+    /// [power_control()]: trait.Adxl345Reader.html#method.power_control
+    fn power_control_lossy(&self) -> AdxlResult<PowerControl> {
+        let register = 0x2d;
+        let value = self.access(register)?;
+        warn_stray_bits(register, value, PowerControl::MASK);
+        Ok(PowerControl::from_bits_truncate(value))
+    }
+    /// Access the current measurement range.
+    ///
+    /// Only the `range` bits (bits 0-1) of the data format register are
+    /// decoded.
+    fn range(&self) -> AdxlResult<Range> {
+        let register = 0x31;
+        Range::try_from(self.access(register)? & 0x03)
+    }
+    /// Access the number of significant bits in each acceleration data value
+    /// for the current data format.
+    ///
+    /// See [DataFormat::resolution_bits()] for more information.
+    ///
+    /// [DataFormat::resolution_bits()]: struct.DataFormat.html#method.resolution_bits
+    fn resolution_bits(&self) -> AdxlResult<u8> {
+        Ok(self.data_format()?.resolution_bits())
+    }
     /// Access the current tap control mode.
     fn tap_control(&self) -> AdxlResult<TapMode> {
         let register = 0x2a;
@@ -256,6 +1883,24 @@ pub trait Adxl345Reader {
 }
 
 /// Write register command set for accelerometer.
+///
+/// Some registers can also be set in physical units with rounding to the
+/// nearest count.
+/// The checked variants return [AdxlError::ValueOutOfRange] when the value
+/// does not fit in the register while the saturating variants clamp it.
+///
+/// | Register     | Checked                     | Saturating                             |
+/// | ------------ | --------------------------- | -------------------------------------- |
+/// | THRESH_TAP   | set_tap_threshold_mg        | set_tap_threshold_mg_saturating        |
+/// | DUR          | set_tap_duration_us         | ___n/a___                              |
+/// | Latent       | set_tap_latency_us          | ___n/a___                              |
+/// | Window       | set_tap_window_us           | ___n/a___                              |
+/// | THRESH_ACT   | set_activity_threshold_mg   | set_activity_threshold_mg_saturating   |
+/// | THRESH_INACT | set_inactivity_threshold_mg | set_inactivity_threshold_mg_saturating |
+/// | THRESH_FF    | set_free_fall_threshold_mg  | set_free_fall_threshold_mg_saturating  |
+/// | TIME_FF      | set_free_fall_time_us       | ___n/a___                              |
+///
+/// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
 pub trait Adxl345Writer {
     //
     // ## Per driver required stuff ##
@@ -290,12 +1935,12 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `thresh` - Threshold value for detecting activity.
-    /// The scale factor is 62.5 mg/LSB.
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the inactivity interrupt is enabled._
+    ///   The scale factor is 62.5 mg/LSB.
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the inactivity interrupt is enabled._
     /// * `time` - Time value representing the amount of time that acceleration
-    /// must be less than the value in `thresh` for inactivity to be declared.
-    /// The scale factor is 1 sec/LSB.
+    ///   must be less than the value in `thresh` for inactivity to be declared.
+    ///   The scale factor is 1 sec/LSB.
     fn set_inactivity(&mut self, thresh: u8, time: u8) -> Result {
         self.set_inactivity_threshold(thresh)?;
         self.set_inactivity_time(time)
@@ -304,16 +1949,16 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `thresh` -  The threshold value for free-fall detection.
-    /// The scale factor is 62.5 mg/LSB.
-    /// Recommended values between 300mg and 600mg (0x14 - 0x46).
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the free-fall interrupt is enabled._
+    ///   The scale factor is 62.5 mg/LSB.
+    ///   Recommended values between 300mg and 600mg (0x14 - 0x46).
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the free-fall interrupt is enabled._
     /// * `time` - Time value representing the minimum time that the value of
-    /// all axes must be less than `thresh` to generate a free-fall interrupt.
-    /// The scale factor is 5 ms/LSB.
-    /// Recommended values between 100ms and 350ms (0x14 - 0x46).
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the free-fall interrupt is enabled._
+    ///   all axes must be less than `thresh` to generate a free-fall interrupt.
+    ///   The scale factor is 5 ms/LSB.
+    ///   Recommended values between 100ms and 350ms (0x14 - 0x46).
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the free-fall interrupt is enabled._
     fn set_free_fall(&mut self, thresh: u8, time: u8) -> Result {
         self.set_free_fall_threshold(thresh)?;
         self.set_free_fall_time(time)
@@ -325,7 +1970,7 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `mode` - Activity mode bit flags.
-    /// See [ActivityMode] bit flags for more info.
+    ///   See [ActivityMode] bit flags for more info.
     ///
     /// [ActivityMode]: struct.ActivityMode.html
     fn set_activity_control<AM>(&mut self, mode: AM) -> Result
@@ -339,101 +1984,211 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `thresh` - Threshold value for detecting activity.
-    /// The scale factor is 62.5 mg/LSB.
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the activity interrupt is enabled._
+    ///   The scale factor is 62.5 mg/LSB.
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the activity interrupt is enabled._
     fn set_activity_threshold(&mut self, thresh: u8) -> Result {
         let register = 0x24;
         self.command(register, thresh)
     }
+    /// Set the activity threshold in mg.
+    ///
+    /// The threshold is rounded to the nearest 62.5 mg count and
+    /// [AdxlError::ValueOutOfRange] is returned for thresholds above 15.9375 g.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_activity_threshold()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_activity_threshold()]: trait.Adxl345Writer.html#method.set_activity_threshold
+    fn set_activity_threshold_mg(&mut self, mg: f64) -> Result {
+        self.set_activity_threshold(checked_count(mg, 62.5)?)
+    }
+    /// Set the activity threshold in mg clamping it to the register range.
+    ///
+    /// Same as [set_activity_threshold_mg()] except thresholds above 15.9375 g set the
+    /// maximum count (0xff) and negative thresholds set 0 instead of
+    /// returning an error.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_activity_threshold()].
+    ///
+    /// [set_activity_threshold()]: trait.Adxl345Writer.html#method.set_activity_threshold
+    /// [set_activity_threshold_mg()]: trait.Adxl345Writer.html#method.set_activity_threshold_mg
+    fn set_activity_threshold_mg_saturating(&mut self, mg: f64) -> Result {
+        self.set_activity_threshold(saturating_count(mg, 62.5))
+    }
     /// Set data rate and power mode control mode options.
     ///
     /// ## Arguments
     /// * `mode` - Data rate and power mode bit flags.
-    /// See [BandwidthRateControl] bit flags for more info.
+    ///   See [BandwidthRateControl] bit flags for more info.
     ///
     /// [BandwidthRateControl]: struct.BandwidthRateControl.html
     fn set_bandwidth_rate<BRC>(&mut self, mode: BRC) -> Result
     where
-        BRC: TryInto<BandwidthRateControl, Error = AdxlError>,
+        BRC: TryInto<BandwidthRateControl>,
+        AdxlError: From<BRC::Error>,
     {
         let register = 0x2c;
         self.command(register, mode.try_into()?.byte[0])
     }
     /// Set data format mode options.
     ///
+    /// A warning is logged when the `self_test` bit is set since the self-test
+    /// force shifts all the readings while it is left on.
+    ///
     /// ## Arguments
     /// * `mode` - Data format mode bit flags.
-    /// See [DataFormat] bit flags for more info.
+    ///   See [DataFormat] bit flags for more info.
     ///
     /// [DataFormat]: struct.DataFormat.html
     fn set_data_format<DF>(&mut self, mode: DF) -> Result
     where
-        DF: TryInto<DataFormat, Error = AdxlError>,
+        DF: TryInto<DataFormat>,
+        AdxlError: From<DF::Error>,
     {
         let register = 0x31;
-        self.command(register, mode.try_into()?.byte[0])
+        let mode = mode.try_into()?;
+        if mode.self_test() {
+            log_warn!("Self-test force enabled, readings are shifted until it is disabled");
+        }
+        self.command(register, mode.byte[0])
     }
     /// Set the free-fall threshold.
     ///
     /// ## Arguments
     /// * `thresh` - Threshold value for detecting activity.
-    /// The scale factor is 62.5 mg/LSB.
-    /// Values between 300 mg and 600 mg(0x05 to 0x09) are recommended.
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the free-fall interrupt is enabled._
+    ///   The scale factor is 62.5 mg/LSB.
+    ///   Values between 300 mg and 600 mg(0x05 to 0x09) are recommended.
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the free-fall interrupt is enabled._
     fn set_free_fall_threshold(&mut self, thresh: u8) -> Result {
         let register = 0x28;
         self.command(register, thresh)
     }
+    /// Set the free-fall threshold in mg.
+    ///
+    /// The threshold is rounded to the nearest 62.5 mg count and
+    /// [AdxlError::ValueOutOfRange] is returned for thresholds above 15.9375 g.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_free_fall_threshold()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_free_fall_threshold()]: trait.Adxl345Writer.html#method.set_free_fall_threshold
+    fn set_free_fall_threshold_mg(&mut self, mg: f64) -> Result {
+        self.set_free_fall_threshold(checked_count(mg, 62.5)?)
+    }
+    /// Set the free-fall threshold in mg clamping it to the register range.
+    ///
+    /// Same as [set_free_fall_threshold_mg()] except thresholds above 15.9375 g set the
+    /// maximum count (0xff) and negative thresholds set 0 instead of
+    /// returning an error.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_free_fall_threshold()].
+    ///
+    /// [set_free_fall_threshold()]: trait.Adxl345Writer.html#method.set_free_fall_threshold
+    /// [set_free_fall_threshold_mg()]: trait.Adxl345Writer.html#method.set_free_fall_threshold_mg
+    fn set_free_fall_threshold_mg_saturating(&mut self, mg: f64) -> Result {
+        self.set_free_fall_threshold(saturating_count(mg, 62.5))
+    }
     /// Set the free-fall time.
     ///
     /// ## Arguments
     /// * `time` - Time value representing the minimum amount of time that
-    /// acceleration must be less than the value in the free-fall threshold
-    /// register for a free-fall interrupt to be generated.
-    /// The scale factor is 5 ms/LSB.
-    /// Values between 100 ms and 350 ms (0x14 to 0x46) are recommended.
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the free-fall interrupt is enabled._
+    ///   acceleration must be less than the value in the free-fall threshold
+    ///   register for a free-fall interrupt to be generated.
+    ///   The scale factor is 5 ms/LSB.
+    ///   Values between 100 ms and 350 ms (0x14 to 0x46) are recommended.
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the free-fall interrupt is enabled._
     fn set_free_fall_time(&mut self, time: u8) -> Result {
         let register = 0x29;
         self.command(register, time)
     }
+    /// Set the free-fall time in μs.
+    ///
+    /// The time is rounded to the nearest 5 ms count and
+    /// [AdxlError::ValueOutOfRange] is returned for times above 1.275 s.
+    ///
+    /// ## Arguments
+    /// * `time_us` - Free-fall time in μs. See [set_free_fall_time()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_free_fall_time()]: trait.Adxl345Writer.html#method.set_free_fall_time
+    fn set_free_fall_time_us(&mut self, time_us: u32) -> Result {
+        self.set_free_fall_time(checked_count(time_us as f64, 5000.0)?)
+    }
     /// Set fifo control mode options.
     ///
+    /// Returns an [AdxlError::InvalidFifoConfig] error without writing
+    /// anything when trigger mode is combined with a `samples` value of 0 or
+    /// when a `samples` (watermark) value is given in bypass mode.
+    ///
     /// ## Arguments
     /// * `mode` - Fifo control mode bit flags.
-    /// See [FifoControl] bit flags for more info.
+    ///   See [FifoControl] bit flags for more info.
     ///
+    /// [AdxlError::InvalidFifoConfig]: enum.AdxlError.html#variant.InvalidFifoConfig
     /// [FifoControl]: struct.FifoControl.html
     fn set_fifo_control<FC>(&mut self, mode: FC) -> Result
     where
         FC: Into<FifoControl>,
     {
         let register = 0x38;
-        self.command(register, mode.into().byte[0])
+        let mode = mode.into();
+        mode.validate()?;
+        self.command(register, mode.byte[0])
     }
     /// Set the inactivity threshold.
     ///
     /// ## Arguments
     /// * `thresh` - Threshold value for detecting activity.
-    /// The scale factor is 62.5 mg/LSB.
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the inactivity interrupt is enabled._
+    ///   The scale factor is 62.5 mg/LSB.
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the inactivity interrupt is enabled._
     fn set_inactivity_threshold(&mut self, thresh: u8) -> Result {
         let register = 0x25;
         self.command(register, thresh)
     }
+    /// Set the inactivity threshold in mg.
+    ///
+    /// The threshold is rounded to the nearest 62.5 mg count and
+    /// [AdxlError::ValueOutOfRange] is returned for thresholds above 15.9375 g.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_inactivity_threshold()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_inactivity_threshold()]: trait.Adxl345Writer.html#method.set_inactivity_threshold
+    fn set_inactivity_threshold_mg(&mut self, mg: f64) -> Result {
+        self.set_inactivity_threshold(checked_count(mg, 62.5)?)
+    }
+    /// Set the inactivity threshold in mg clamping it to the register range.
+    ///
+    /// Same as [set_inactivity_threshold_mg()] except thresholds above 15.9375 g set the
+    /// maximum count (0xff) and negative thresholds set 0 instead of
+    /// returning an error.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_inactivity_threshold()].
+    ///
+    /// [set_inactivity_threshold()]: trait.Adxl345Writer.html#method.set_inactivity_threshold
+    /// [set_inactivity_threshold_mg()]: trait.Adxl345Writer.html#method.set_inactivity_threshold_mg
+    fn set_inactivity_threshold_mg_saturating(&mut self, mg: f64) -> Result {
+        self.set_inactivity_threshold(saturating_count(mg, 62.5))
+    }
     /// Set the inactivity time.
     ///
     /// ## Arguments
     /// * `time` - Time value representing the amount of time that acceleration
-    /// must be less than the value in the inactivity threshold register for
-    /// inactivity to be declared.
-    /// The scale factor is 1 sec/LSB.
-    /// ___Note:___ _that a value of 0 results in an interrupt when the output
-    /// data is less than the threshold._
+    ///   must be less than the value in the inactivity threshold register for
+    ///   inactivity to be declared.
+    ///   The scale factor is 1 sec/LSB.
+    ///   ___Note:___ _that a value of 0 results in an interrupt when the output
+    ///   data is less than the threshold._
     fn set_inactivity_time(&mut self, time: u8) -> Result {
         let register = 0x26;
         self.command(register, time)
@@ -442,7 +2197,7 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `mode` - Interrupt control mode bit flags.
-    /// See [IntControlMode] bit flags for more info.
+    ///   See [IntControlMode] bit flags for more info.
     ///
     /// [IntControlMode]: struct.IntControlMode.html
     fn set_interrupt_control<IC>(&mut self, mode: IC) -> Result
@@ -456,7 +2211,7 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `mode` - Interrupt mapping mode bit flags.
-    /// See [IntMapMode] bit flags for more info.
+    ///   See [IntMapMode] bit flags for more info.
     ///
     /// [IntMapMode]: struct.IntMapMode.html
     fn set_interrupt_map<IM>(&mut self, mode: IM) -> Result
@@ -468,52 +2223,70 @@ pub trait Adxl345Writer {
     }
     /// Use to set one or more axis offset adjustments.
     ///
+    /// Only the given axes are written.
+    /// The drivers in this crate write all three contiguous offset registers
+    /// in a single bus transaction when all three values are given.
+    ///
     /// ## Arguments
     /// * `x` - X-axis offset adjustment value in twos complement format
-    /// with a scale factor of 15.6 mg/LSB.
-    /// Automatically added to the acceleration data before storing in the data
-    /// register.
-    /// A `None` value leaves the existing offset adjustment unchanged.
+    ///   with a scale factor of 15.6 mg/LSB.
+    ///   Automatically added to the acceleration data before storing in the data
+    ///   register.
+    ///   A `None` value leaves the existing offset adjustment unchanged.
     /// * `y` - Y-axis offset adjustment value in twos complement format
-    /// with a scale factor of 15.6 mg/LSB.
-    /// Automatically added to the acceleration data before storing in the data
-    /// register.
-    /// A `None` value leaves the existing offset adjustment unchanged.
+    ///   with a scale factor of 15.6 mg/LSB.
+    ///   Automatically added to the acceleration data before storing in the data
+    ///   register.
+    ///   A `None` value leaves the existing offset adjustment unchanged.
     /// * `z` - Z-axis offset adjustment value in twos complement format
-    /// with a scale factor of 15.6 mg/LSB.
-    /// Automatically added to the acceleration data before storing in the data
-    /// register.
-    /// A `None` value leaves the existing offset adjustment unchanged.
+    ///   with a scale factor of 15.6 mg/LSB.
+    ///   Automatically added to the acceleration data before storing in the data
+    ///   register.
+    ///   A `None` value leaves the existing offset adjustment unchanged.
     fn set_offset_adjustment<X, Y, Z>(&mut self, x: X, y: Y, z: Z) -> Result
     where
         X: Into<Option<i8>>,
         Y: Into<Option<i8>>,
         Z: Into<Option<i8>>,
     {
-        let x = x.into();
-        let y = y.into();
-        let z = z.into();
-        if let Some(x) = x {
-            self.set_x_offset(x)?
-        };
-        if let Some(y) = y {
-            self.set_x_offset(y)?
-        };
-        if let Some(z) = z {
-            self.set_x_offset(z)?
-        };
-        Ok(())
+        write_offsets(self, x.into(), y.into(), z.into())
+    }
+    /// Use to set one or more axis offset adjustments in g.
+    ///
+    /// Values are converted using the fixed 15.6 mg/LSB scale factor, rounded
+    /// to the nearest count, and clamped to the two's complement range of the
+    /// registers (-128 to 127 counts or about -1.997 g to 1.981 g).
+    ///
+    /// ## Arguments
+    /// * `x` - X-axis offset adjustment value in g.
+    ///   A `None` value leaves the existing offset adjustment unchanged.
+    /// * `y` - Y-axis offset adjustment value in g.
+    ///   A `None` value leaves the existing offset adjustment unchanged.
+    /// * `z` - Z-axis offset adjustment value in g.
+    ///   A `None` value leaves the existing offset adjustment unchanged.
+    fn set_offset_adjustment_g<X, Y, Z>(&mut self, x: X, y: Y, z: Z) -> Result
+    where
+        X: Into<Option<f64>>,
+        Y: Into<Option<f64>>,
+        Z: Into<Option<f64>>,
+    {
+        self.set_offset_adjustment(
+            x.into().map(offset_g_to_counts),
+            y.into().map(offset_g_to_counts),
+            z.into().map(offset_g_to_counts),
+        )
     }
     /// Set power-saving features control mode options.
     ///
     /// ## Arguments
     /// * `mode` - Power-saving features bit flags.
-    /// See [PowerControl] bit flags for more info.
+    ///   See [PowerControl] bit flags for more info.
     ///
     /// [PowerControl]: struct.PowerControl.html
     fn set_power_control<PC>(&mut self, mode: PC) -> Result
     where
-        PC: TryInto<PowerControl, Error = AdxlError>,
+        PC: TryInto<PowerControl>,
+        AdxlError: From<PC::Error>,
     {
         let register = 0x2d;
         self.command(register, mode.try_into()?.byte[0])
@@ -522,12 +2295,16 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `tap` - Containing values for `threshold`, `duration`, `latency`, and
-    /// `window` registers.
+    ///   `window` registers.
+    ///   A [TapConfig] in physical units can also be used.
+    ///
+    /// [TapConfig]: struct.TapConfig.html
     fn set_tap<T>(&mut self, tap: T) -> Result
     where
-        T: Into<Tap>,
+        T: TryInto<Tap>,
+        AdxlError: From<T::Error>,
     {
-        let tap = tap.into();
+        let tap = tap.try_into()?;
         self.set_tap_threshold(tap.threshold)?;
         self.set_tap_duration(tap.duration)?;
         self.set_tap_latency(tap.latency)?;
@@ -537,7 +2314,7 @@ pub trait Adxl345Writer {
     ///
     /// ## Arguments
     /// * `mode` - Tab mode bit flags.
-    /// See [TapMode] bit flags for more info.
+    ///   See [TapMode] bit flags for more info.
     ///
     /// [TapMode]: struct.TapMode.html
     fn set_tap_control<TM>(&mut self, mode: TM) -> Result
@@ -558,6 +2335,19 @@ pub trait Adxl345Writer {
         let register = 0x21;
         self.command(register, duration)
     }
+    /// Set the tap duration in μs.
+    ///
+    /// The time is rounded to the nearest 625 μs count and
+    /// [AdxlError::ValueOutOfRange] is returned for times above 159.375 ms.
+    ///
+    /// ## Arguments
+    /// * `duration_us` - Tap duration in μs. See [set_tap_duration()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_tap_duration()]: trait.Adxl345Writer.html#method.set_tap_duration
+    fn set_tap_duration_us(&mut self, duration_us: u32) -> Result {
+        self.set_tap_duration(checked_count(duration_us as f64, 625.0)?)
+    }
     /// Set latency for double tap events.
     ///
     /// ## Arguments
@@ -571,6 +2361,19 @@ pub trait Adxl345Writer {
         let register = 0x22;
         self.command(register, latency)
     }
+    /// Set the double tap latency in μs.
+    ///
+    /// The time is rounded to the nearest 1.25 ms count and
+    /// [AdxlError::ValueOutOfRange] is returned for times above 318.75 ms.
+    ///
+    /// ## Arguments
+    /// * `latency_us` - Double tap latency in μs. See [set_tap_latency()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_tap_latency()]: trait.Adxl345Writer.html#method.set_tap_latency
+    fn set_tap_latency_us(&mut self, latency_us: u32) -> Result {
+        self.set_tap_latency(checked_count(latency_us as f64, 1250.0)?)
+    }
     /// Set threshold for tap events.
     ///
     /// ___Note:___ _that a value of 0 may result in undesirable behavior if
@@ -583,6 +2386,33 @@ pub trait Adxl345Writer {
         let register = 0x1d;
         self.command(register, threshold)
     }
+    /// Set the tap threshold in mg.
+    ///
+    /// The threshold is rounded to the nearest 62.5 mg count and
+    /// [AdxlError::ValueOutOfRange] is returned for thresholds above 15.9375 g.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_tap_threshold()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_tap_threshold()]: trait.Adxl345Writer.html#method.set_tap_threshold
+    fn set_tap_threshold_mg(&mut self, mg: f64) -> Result {
+        self.set_tap_threshold(checked_count(mg, 62.5)?)
+    }
+    /// Set the tap threshold in mg clamping it to the register range.
+    ///
+    /// Same as [set_tap_threshold_mg()] except thresholds above 15.9375 g set the
+    /// maximum count (0xff) and negative thresholds set 0 instead of
+    /// returning an error.
+    ///
+    /// ## Arguments
+    /// * `mg` - Threshold in mg. See [set_tap_threshold()].
+    ///
+    /// [set_tap_threshold()]: trait.Adxl345Writer.html#method.set_tap_threshold
+    /// [set_tap_threshold_mg()]: trait.Adxl345Writer.html#method.set_tap_threshold_mg
+    fn set_tap_threshold_mg_saturating(&mut self, mg: f64) -> Result {
+        self.set_tap_threshold(saturating_count(mg, 62.5))
+    }
     /// Set window for double tap events.
     ///
     /// ## Arguments
@@ -595,6 +2425,19 @@ pub trait Adxl345Writer {
         let register = 0x23;
         self.command(register, window)
     }
+    /// Set the double tap window in μs.
+    ///
+    /// The time is rounded to the nearest 1.25 ms count and
+    /// [AdxlError::ValueOutOfRange] is returned for times above 318.75 ms.
+    ///
+    /// ## Arguments
+    /// * `window_us` - Double tap window in μs. See [set_tap_window()].
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    /// [set_tap_window()]: trait.Adxl345Writer.html#method.set_tap_window
+    fn set_tap_window_us(&mut self, window_us: u32) -> Result {
+        self.set_tap_window(checked_count(window_us as f64, 1250.0)?)
+    }
     /// Set the x-axis offset adjustment.
     ///
     /// ## Arguments
@@ -624,24 +2467,55 @@ pub trait Adxl345Writer {
     }
 }
 
+/// Register writes done by device initialization in the order they are done.
+///
+/// ## Arguments
+/// * `spi_3wire` - true: SPI 3-wire mode; false: SPI 4-wire or I²C mode.
+fn init_sequence(spi_3wire: bool) -> impl Iterator<Item = (u8, u8)> {
+    let data_format = (0x31, if spi_3wire { 1 << 6 } else { 0 });
+    std::iter::once(data_format)
+        .chain((0x1du8..=0x2a).map(|register| (register, 0)))
+        .chain(std::iter::once((0x2c, 0x0a)))
+        .chain((0x2du8..=0x2f).map(|register| (register, 0)))
+        .chain(std::iter::once((0x38, 0)))
+}
+
 pub(crate) trait Adxl345Init: Adxl345Writer {
     fn init_registers(&mut self, spi_3wire: bool) -> Result {
-        let register = 0x31;
-        self.command(register, if spi_3wire { 1 << 6 } else { 0 })?;
-        for register in 0x1du8..=0x2a {
-            self.command(register, 0)?;
+        for (register, byte) in init_sequence(spi_3wire) {
+            self.command(register, byte)?;
         }
-        let register = 0x2c;
-        self.command(register, 0x0a)?;
-        for register in 0x2du8..=0x2f {
-            self.command(register, 0)?;
+        Ok(())
+    }
+    /// Initializes the device registers and then reads each one back,
+    /// returning [AdxlError::VerifyMismatch] on the first that differs.
+    ///
+    /// [AdxlError::VerifyMismatch]: enum.AdxlError.html#variant.VerifyMismatch
+    fn init_registers_verified(&mut self, spi_3wire: bool) -> Result
+    where
+        Self: Adxl345Reader,
+    {
+        self.init_registers(spi_3wire)?;
+        for (register, byte) in init_sequence(spi_3wire) {
+            verify_register(self, register, byte)?;
         }
-        let register = 0x38;
-        self.command(register, 0)?;
         Ok(())
     }
 }
 
+/// Active logic level of the interrupt pins used by the
+/// [set_interrupt_active_level()] method.
+///
+/// [set_interrupt_active_level()]: trait.Adxl345.html#method.set_interrupt_active_level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ActiveLevel {
+    /// Interrupt pins are active high (the `int_invert` bit is cleared).
+    High,
+    /// Interrupt pins are active low (the `int_invert` bit is set).
+    Low,
+}
+
 // Activity/Inactivity control mode.
 bitflags! {
     /// Activity mode bit flags used in [activity_control()] and
@@ -650,6 +2524,7 @@ bitflags! {
     /// [activity_control()]: trait.Adxl345Reader.html#method.activity_control
     /// [set_activity_control()]: trait.Adxl345Writer.html#method.set_activity_control
     #[derive(Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
     pub struct ActivityMode: u8 {
         /// Select activity AC-coupled operation.
         const ACT_AC = 0x80;
@@ -686,6 +2561,119 @@ bitflags! {
     }
 }
 
+impl ActivityMode {
+    /// Starts building an activity mode with all axes disabled and DC-coupled
+    /// operation.
+    ///
+    /// See [ActivityModeBuilder] for more information.
+    ///
+    /// [ActivityModeBuilder]: struct.ActivityModeBuilder.html
+    pub fn builder() -> ActivityModeBuilder {
+        ActivityModeBuilder::default()
+    }
+    /// Check if activity detection uses AC-coupled operation.
+    pub fn act_ac_coupled(&self) -> bool {
+        self.contains(Self::ACT_AC)
+    }
+    /// Check if the X-axis participates in detecting activity.
+    pub fn act_x_enabled(&self) -> bool {
+        self.contains(Self::ACT_X_ENABLE)
+    }
+    /// Check if the Y-axis participates in detecting activity.
+    pub fn act_y_enabled(&self) -> bool {
+        self.contains(Self::ACT_Y_ENABLE)
+    }
+    /// Check if the Z-axis participates in detecting activity.
+    pub fn act_z_enabled(&self) -> bool {
+        self.contains(Self::ACT_Z_ENABLE)
+    }
+    /// Check if inactivity detection uses AC-coupled operation.
+    pub fn inact_ac_coupled(&self) -> bool {
+        self.contains(Self::INACT_AC)
+    }
+    /// Check if the X-axis participates in detecting inactivity.
+    pub fn inact_x_enabled(&self) -> bool {
+        self.contains(Self::INACT_X_ENABLE)
+    }
+    /// Check if the Y-axis participates in detecting inactivity.
+    pub fn inact_y_enabled(&self) -> bool {
+        self.contains(Self::INACT_Y_ENABLE)
+    }
+    /// Check if the Z-axis participates in detecting inactivity.
+    pub fn inact_z_enabled(&self) -> bool {
+        self.contains(Self::INACT_Z_ENABLE)
+    }
+    /// Iterates over each set flag as a single flag value.
+    pub fn iter(&self) -> impl Iterator<Item = Self> {
+        set_bits(self.bits()).map(Self::from_bits_truncate)
+    }
+}
+
+impl From<u8> for ActivityMode {
+    /// Converts a raw byte silently dropping (truncating) any undefined bits.
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+/// Builder for [ActivityMode] which sets the axes and coupling of activity and
+/// inactivity detection without OR-ing the individual flags.
+///
+/// [ActivityMode]: struct.ActivityMode.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ActivityModeBuilder {
+    mode: ActivityMode,
+}
+
+impl ActivityModeBuilder {
+    /// Selects the axes which participate in detecting activity.
+    ///
+    /// ## Arguments
+    /// * `x` - true: X-axis enabled; false: X-axis disabled.
+    /// * `y` - true: Y-axis enabled; false: Y-axis disabled.
+    /// * `z` - true: Z-axis enabled; false: Z-axis disabled.
+    pub fn activity_axes(mut self, x: bool, y: bool, z: bool) -> Self {
+        self.mode.set(ActivityMode::ACT_X_ENABLE, x);
+        self.mode.set(ActivityMode::ACT_Y_ENABLE, y);
+        self.mode.set(ActivityMode::ACT_Z_ENABLE, z);
+        self
+    }
+    /// Selects the coupling used in detecting activity.
+    ///
+    /// ## Arguments
+    /// * `coupling` - AC or DC-coupled operation.
+    pub fn activity_coupling(mut self, coupling: Coupling) -> Self {
+        self.mode
+            .set(ActivityMode::ACT_AC, coupling == Coupling::Ac);
+        self
+    }
+    /// Finishes building the activity mode.
+    pub fn build(self) -> ActivityMode {
+        self.mode
+    }
+    /// Selects the axes which participate in detecting inactivity.
+    ///
+    /// ## Arguments
+    /// * `x` - true: X-axis enabled; false: X-axis disabled.
+    /// * `y` - true: Y-axis enabled; false: Y-axis disabled.
+    /// * `z` - true: Z-axis enabled; false: Z-axis disabled.
+    pub fn inactivity_axes(mut self, x: bool, y: bool, z: bool) -> Self {
+        self.mode.set(ActivityMode::INACT_X_ENABLE, x);
+        self.mode.set(ActivityMode::INACT_Y_ENABLE, y);
+        self.mode.set(ActivityMode::INACT_Z_ENABLE, z);
+        self
+    }
+    /// Selects the coupling used in detecting inactivity.
+    ///
+    /// ## Arguments
+    /// * `coupling` - AC or DC-coupled operation.
+    pub fn inactivity_coupling(mut self, coupling: Coupling) -> Self {
+        self.mode
+            .set(ActivityMode::INACT_AC, coupling == Coupling::Ac);
+        self
+    }
+}
+
 // Activity/tap status.
 bitflags! {
     /// Activity/Tap Status bit flags returned by [activity_tap_status()] method.
@@ -711,19 +2699,38 @@ bitflags! {
     }
 }
 
+impl ATStatus {
+    /// Iterates over each set flag as a single flag value.
+    pub fn iter(&self) -> impl Iterator<Item = Self> {
+        set_bits(self.bits()).map(Self::from_bits_truncate)
+    }
+}
+
+impl From<u8> for ATStatus {
+    /// Converts a raw byte silently dropping (truncating) any undefined bits.
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
 /// Bandwidth rate control bitfields used in [bandwidth_rate()] and
 /// [set_bandwidth_rate()] methods.
 ///
 /// [bandwidth_rate()]: trait.Adxl345Reader.html#method.bandwidth_rate
 /// [set_bandwidth_rate()]: trait.Adxl345Writer.html#method.set_bandwidth_rate
 #[repr(C, align(1))]
-#[derive(BitfieldStruct, Clone, Copy)]
+#[derive(BitfieldStruct, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct BandwidthRateControl {
     /// Bit fields:
     /// * `low_power` - (Bit 4) Selects reduced power operation, which has
-    /// somewhat higher noise level.
+    ///   somewhat higher noise level.
     /// * `rate` - (Bits 0-3) Select the device bandwidth and output data rate.
-    /// See the table below for more information.
+    ///   See the table below for more information.
     ///
     /// Power mode table:
     ///
@@ -754,11 +2761,44 @@ pub struct BandwidthRateControl {
     byte: [u8; 1],
 }
 
+impl BandwidthRateControl {
+    /// Mask of the defined bitfields.
+    ///
+    /// Bits 5-7 are reserved.
+    const MASK: u8 = 0x1f;
+    /// Lenient constructor which clears any set reserved bits (bits 5-7) instead
+    /// of failing like `try_from()` does.
+    ///
+    /// ## Arguments
+    /// * `value` - Raw register byte.
+    pub fn from_bits_truncate(value: u8) -> Self {
+        Self {
+            byte: [value & Self::MASK; 1],
+        }
+    }
+    /// Returns a copy with the `low_power` bit changed.
+    ///
+    /// ## Arguments
+    /// * `low_power` - true: reduced power operation; false: normal operation.
+    pub fn with_low_power(mut self, low_power: bool) -> Self {
+        self.set_low_power(low_power);
+        self
+    }
+    /// Returns a copy with the `rate` bits changed.
+    ///
+    /// ## Arguments
+    /// * `rate` - New output data rate.
+    pub fn with_rate(mut self, rate: OutputDataRate) -> Self {
+        self.set_rate(rate as u8);
+        self
+    }
+}
+
 impl TryFrom<u8> for BandwidthRateControl {
     type Error = AdxlError;
     fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
         // Bit-wise AND with negative mask of allowed bitfields.
-        if value & !0x1f == 0 {
+        if value & !Self::MASK == 0 {
             Ok(Self { byte: [value; 1] })
         } else {
             Err(AdxlError::UnknownModeBit(value))
@@ -766,6 +2806,30 @@ impl TryFrom<u8> for BandwidthRateControl {
     }
 }
 
+impl From<BandwidthRateControl> for u8 {
+    /// Converts back into the raw register byte.
+    fn from(value: BandwidthRateControl) -> Self {
+        value.byte[0]
+    }
+}
+
+/// Coupling used by activity and inactivity detection and set with
+/// [ActivityModeBuilder].
+///
+/// In DC-coupled operation the acceleration is compared directly to the
+/// threshold while in AC-coupled operation it is compared to a reference
+/// acceleration taken at the start of detection.
+///
+/// [ActivityModeBuilder]: struct.ActivityModeBuilder.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Coupling {
+    /// AC-coupled operation.
+    Ac,
+    /// DC-coupled operation.
+    Dc,
+}
+
 /// Data format bitfields used in [data_format()] and [set_data_format()]
 /// methods.
 ///
@@ -776,25 +2840,30 @@ impl TryFrom<u8> for BandwidthRateControl {
 /// [data_format()]: trait.Adxl345Reader.html#method.data_format
 /// [set_data_format()]: trait.Adxl345Writer.html#method.set_data_format
 #[repr(C, align(1))]
-#[derive(BitfieldStruct, Clone, Copy)]
+#[derive(BitfieldStruct, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct DataFormat {
     /// Bit fields:
     /// * `self_test` - (Bit 7) A `true` applies a self-test force to the sensor,
-    /// causing a shift in the output data.
-    /// A `false` disable the self-test force.
+    ///   causing a shift in the output data.
+    ///   A `false` disable the self-test force.
     /// * `spi` - (Bit 6) A `true` sets 3-wire SPI mode and `false` 4-wire mode.
     /// * `int_invert` - (Bit 5) A `true` switches the interrupt pins to active
-    /// low, while `false` sets them to active high.
+    ///   low, while `false` sets them to active high.
     /// * `full_res` - (Bit 3) When `true` puts the device into full resolution
-    /// mode where the output resolution increases with the g-force range while
-    /// maintaining a 3.9mg/LSB scale factor.
-    /// When `false` the device is in 10-bit mode where the `range` bitfield
-    /// determines the maximum g-force range and scale factor.
-    /// See the table below for more information.
+    ///   mode where the output resolution increases with the g-force range while
+    ///   maintaining a 3.9mg/LSB scale factor.
+    ///   When `false` the device is in 10-bit mode where the `range` bitfield
+    ///   determines the maximum g-force range and scale factor.
+    ///   See the table below for more information.
     /// * `justify` - (Bit 2) When `true` selects left-justified (MSB) mode.
-    /// A `false` selects right-justified mode with sign extension.
+    ///   A `false` selects right-justified mode with sign extension.
     /// * `range` - (Bits 0-1) Controls the g-force range and scale factor of
-    /// readings as described in table.
+    ///   readings as described in table.
     ///
     /// g-force range table:
     ///
@@ -805,13 +2874,136 @@ pub struct DataFormat {
     /// |  &pm; 8g | 15.6mg |           10 |
     /// | &pm; 16g | 31.2mg |           11 |
     ///
-    #[bitfield(name = "self_test", ty = "bool", bits = "7..=7")]
-    #[bitfield(name = "spi", ty = "bool", bits = "6..=6")]
-    #[bitfield(name = "int_invert", ty = "bool", bits = "5..=5")]
-    #[bitfield(name = "full_res", ty = "bool", bits = "3..=3")]
-    #[bitfield(name = "justify", ty = "bool", bits = "2..=2")]
-    #[bitfield(name = "range", ty = "u8", bits = "0..=1")]
-    byte: [u8; 1],
+    #[bitfield(name = "self_test", ty = "bool", bits = "7..=7")]
+    #[bitfield(name = "spi", ty = "bool", bits = "6..=6")]
+    #[bitfield(name = "int_invert", ty = "bool", bits = "5..=5")]
+    #[bitfield(name = "full_res", ty = "bool", bits = "3..=3")]
+    #[bitfield(name = "justify", ty = "bool", bits = "2..=2")]
+    #[bitfield(name = "range", ty = "u8", bits = "0..=1")]
+    byte: [u8; 1],
+}
+
+impl DataFormat {
+    /// Mask of the defined bitfields.
+    ///
+    /// Bit 4 is reserved.
+    /// The defined bits are 7, 6, 5, 3, 2, 1, and 0 which is `0xef`, so
+    /// `value & !MASK` is `0x10` exactly when the reserved bit is set and such
+    /// values are rejected by `try_from()` with [AdxlError::UnknownModeBit].
+    ///
+    /// [AdxlError::UnknownModeBit]: enum.AdxlError.html#variant.UnknownModeBit
+    const MASK: u8 = 0xef;
+    /// Lenient constructor which clears any set reserved bits (bit 4) instead
+    /// of failing like `try_from()` does.
+    ///
+    /// ## Arguments
+    /// * `value` - Raw register byte.
+    pub fn from_bits_truncate(value: u8) -> Self {
+        Self {
+            byte: [value & Self::MASK; 1],
+        }
+    }
+    /// Number of significant bits in each acceleration data value.
+    ///
+    /// In 10-bit mode this is always 10 while in full resolution mode it grows
+    /// with the `range` bitfield from 10 bits at ±2 g to 13 bits at ±16 g.
+    pub fn resolution_bits(&self) -> u8 {
+        if self.full_res() {
+            10 + self.range()
+        } else {
+            10
+        }
+    }
+    /// Converts a raw acceleration data value into a right-justified, sign
+    /// extended value.
+    ///
+    /// In right-justified mode the device already sign extends the value so
+    /// it is returned unchanged while in left-justified mode the significant
+    /// bits are shifted down from the MSB with sign extension.
+    /// Either way the result can then be multiplied by [scale_factor()].
+    ///
+    /// Raw values of ±1 g for representative formats:
+    ///
+    /// | `full_res` | `range` | `justify` | Bits | +1 g raw | -1 g raw | Result    |
+    /// | ---------- | ------: | --------- | ---: | -------: | -------: | --------: |
+    /// | false      |    ±2 g | right     |   10 | `0x0100` | `0xff00` |  ±256     |
+    /// | false      |    ±2 g | left      |   10 | `0x4000` | `0xc000` |  ±256     |
+    /// | false      |   ±16 g | right     |   10 | `0x0020` | `0xffe0` |   ±32     |
+    /// | false      |   ±16 g | left      |   10 | `0x0800` | `0xf800` |   ±32     |
+    /// | true       |    ±2 g | left      |   10 | `0x4000` | `0xc000` |  ±256     |
+    /// | true       |   ±16 g | right     |   13 | `0x0100` | `0xff00` |  ±256     |
+    /// | true       |   ±16 g | left      |   13 | `0x0800` | `0xf800` |  ±256     |
+    ///
+    /// ## Arguments
+    /// * `raw` - Value as read from a pair of data registers.
+    ///
+    /// [scale_factor()]: struct.DataFormat.html#method.scale_factor
+    pub fn sign_extend_if_needed(&self, raw: i16) -> i16 {
+        if self.justify() {
+            raw >> (16 - self.resolution_bits())
+        } else {
+            raw
+        }
+    }
+    /// Scale factor of the acceleration data registers in g/LSB.
+    ///
+    /// In full resolution mode this is always 3.9 mg/LSB (1/256 g) else it
+    /// doubles with each step of the `range` bitfield.
+    pub fn scale_factor(&self) -> f64 {
+        if self.full_res() {
+            1.0 / 256.0
+        } else {
+            (1u16 << self.range()) as f64 / 256.0
+        }
+    }
+    /// Returns a copy with the `full_res` bit changed.
+    ///
+    /// ## Arguments
+    /// * `full_res` - true: full resolution mode; false: 10-bit mode.
+    pub fn with_full_res(mut self, full_res: bool) -> Self {
+        self.set_full_res(full_res);
+        self
+    }
+    /// Returns a copy with the `int_invert` bit changed.
+    ///
+    /// ## Arguments
+    /// * `int_invert` - true: interrupts active low; false: active high.
+    pub fn with_int_invert(mut self, int_invert: bool) -> Self {
+        self.set_int_invert(int_invert);
+        self
+    }
+    /// Returns a copy with the `justify` bit changed.
+    ///
+    /// ## Arguments
+    /// * `justify` - true: left-justified (MSB) mode; false: right-justified mode.
+    pub fn with_justify(mut self, justify: bool) -> Self {
+        self.set_justify(justify);
+        self
+    }
+    /// Returns a copy with the `range` bits changed.
+    ///
+    /// ## Arguments
+    /// * `range` - New measurement range.
+    pub fn with_range(mut self, range: Range) -> Self {
+        self.set_range(range as u8);
+        self
+    }
+    /// Returns a copy with the `self_test` bit changed.
+    ///
+    /// ## Arguments
+    /// * `self_test` - true: apply the self-test force; false: normal operation.
+    pub fn with_self_test(mut self, self_test: bool) -> Self {
+        self.set_self_test(self_test);
+        self
+    }
+    /// Returns a copy with the `spi` bit changed.
+    ///
+    /// ## Arguments
+    /// * `spi` - true: SPI 3-wire mode; false: SPI 4-wire mode.
+    pub fn with_spi(mut self, spi: bool) -> Self {
+        self.set_spi(spi);
+        self
+    }
 }
 
 impl TryFrom<u8> for DataFormat {
@@ -819,7 +3011,7 @@ impl TryFrom<u8> for DataFormat {
     //noinspection DuplicatedCode
     fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
         // Bit-wise AND with negative mask of allowed bitfields.
-        if value & !0xef == 0 {
+        if value & !Self::MASK == 0 {
             Ok(Self { byte: [value; 1] })
         } else {
             Err(AdxlError::UnknownModeBit(value))
@@ -827,13 +3019,21 @@ impl TryFrom<u8> for DataFormat {
     }
 }
 
+impl From<DataFormat> for u8 {
+    /// Converts back into the raw register byte.
+    fn from(value: DataFormat) -> Self {
+        value.byte[0]
+    }
+}
+
 /// Fifo buffer control bitfields used in [fifo_control()] and
 /// [set_fifo_control()] methods.
 ///
 /// [fifo_control()]: trait.Adxl345Reader.html#method.fifo_control
 /// [set_fifo_control()]: trait.Adxl345Writer.html#method.set_fifo_control
 #[repr(C, align(1))]
-#[derive(BitfieldStruct, Clone, Copy)]
+#[derive(BitfieldStruct, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "u8"))]
 pub struct FifoControl {
     /// Bit fields:
     /// * `fifo_mode` - (Bits 6-7) One of the fifo modes:
@@ -847,11 +3047,11 @@ pub struct FifoControl {
     ///
     /// * `trigger` - (Bit 5) When `true` links trigger event to INT2 else to INT1.
     /// * `samples` - (Bits 0-4) The function of these bits depends on the `fifo_mode`.
-    /// See the table below for `fifo_mode` vs `samples` function.
-    /// A 0 value will immediately set the `watermark` bit in the interrupt
-    /// source register regardless of the FIFO mode.
-    /// ___Note:___ _A 0 value should never be used when `fifo_mode` is set to
-    /// trigger mode._
+    ///   See the table below for `fifo_mode` vs `samples` function.
+    ///   A 0 value will immediately set the `watermark` bit in the interrupt
+    ///   source register regardless of the FIFO mode.
+    ///   ___Note:___ _A 0 value should never be used when `fifo_mode` is set to
+    ///   trigger mode._
     ///
     /// Samples bits functions:
     ///
@@ -868,34 +3068,213 @@ pub struct FifoControl {
     byte: [u8; 1],
 }
 
+impl FifoControl {
+    /// Largest value that fits in the 5-bit `samples` bitfield.
+    const MAX_SAMPLES: u8 = 0x1f;
+    /// Bypass mode constructor.
+    ///
+    /// The FIFO is bypassed and the `samples` bitfield is left as 0.
+    pub fn new_bypass() -> Self {
+        Self { byte: [0u8; 1] }
+    }
+    /// FIFO mode constructor.
+    ///
+    /// ## Arguments
+    /// * `watermark` - Number of FIFO entries (0-31) needed before the
+    ///   `watermark` interrupt triggers.
+    pub fn new_fifo(watermark: u8) -> AdxlResult<Self> {
+        Self::with_mode(0b01, false, watermark)
+    }
+    /// Stream mode constructor.
+    ///
+    /// ## Arguments
+    /// * `watermark` - Number of FIFO entries (0-31) needed before the
+    ///   `watermark` interrupt triggers.
+    pub fn new_stream(watermark: u8) -> AdxlResult<Self> {
+        Self::with_mode(0b10, false, watermark)
+    }
+    /// Trigger mode constructor.
+    ///
+    /// ## Arguments
+    /// * `samples` - How many FIFO entries (1-31) to retain before the trigger
+    ///   event.
+    ///   ___Note:___ _A 0 value is rejected since the datasheet warns it should
+    ///   never be used in trigger mode._
+    /// * `int2` - When `true` links the trigger event to INT2 else to INT1.
+    pub fn new_trigger(samples: u8, int2: bool) -> AdxlResult<Self> {
+        if samples == 0 {
+            return Err(AdxlError::InvalidFifoConfig(samples));
+        }
+        Self::with_mode(0b11, int2, samples)
+    }
+    /// Returns a copy with the `fifo_mode` bits changed.
+    ///
+    /// ## Arguments
+    /// * `mode` - New fifo mode.
+    pub fn with_fifo_mode(mut self, mode: FifoMode) -> Self {
+        self.set_fifo_mode(mode as u8);
+        self
+    }
+    /// Returns a copy with the `samples` bits changed.
+    ///
+    /// Returns [AdxlError::InvalidFifoConfig] if `samples` does not fit in the
+    /// 5-bit bitfield.
+    ///
+    /// ## Arguments
+    /// * `samples` - Watermark or trigger sample count (0-31).
+    ///
+    /// [AdxlError::InvalidFifoConfig]: enum.AdxlError.html#variant.InvalidFifoConfig
+    pub fn with_samples(mut self, samples: u8) -> AdxlResult<Self> {
+        if samples > Self::MAX_SAMPLES {
+            return Err(AdxlError::InvalidFifoConfig(samples));
+        }
+        self.set_samples(samples);
+        Ok(self)
+    }
+    /// Returns a copy with the `trigger` bit changed.
+    ///
+    /// ## Arguments
+    /// * `trigger` - true: trigger event linked to INT2; false: to INT1.
+    pub fn with_trigger(mut self, trigger: bool) -> Self {
+        self.set_trigger(trigger);
+        self
+    }
+    /// Checks for hazardous `fifo_mode` and `samples` combinations.
+    pub(crate) fn validate(&self) -> Result {
+        match (self.fifo_mode(), self.samples()) {
+            (0b00, samples) if samples != 0 => Err(AdxlError::InvalidFifoConfig(samples)),
+            (0b11, 0) => Err(AdxlError::InvalidFifoConfig(0)),
+            _ => Ok(()),
+        }
+    }
+    fn with_mode(fifo_mode: u8, trigger: bool, samples: u8) -> AdxlResult<Self> {
+        if samples > Self::MAX_SAMPLES {
+            return Err(AdxlError::InvalidFifoConfig(samples));
+        }
+        let mut control = Self::new_bypass();
+        control.set_fifo_mode(fifo_mode);
+        control.set_trigger(trigger);
+        control.set_samples(samples);
+        Ok(control)
+    }
+}
+
 impl From<u8> for FifoControl {
     fn from(value: u8) -> Self {
         Self { byte: [value; 1] }
     }
 }
 
+impl From<FifoControl> for u8 {
+    /// Converts back into the raw register byte.
+    fn from(value: FifoControl) -> Self {
+        value.byte[0]
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FifoControl {
+    /// Deserializes the raw register byte rejecting the same hazardous
+    /// `fifo_mode` and `samples` combinations as [set_fifo_control()].
+    ///
+    /// [set_fifo_control()]: trait.Adxl345Writer.html#method.set_fifo_control
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let control = FifoControl::from(u8::deserialize(deserializer)?);
+        control.validate().map_err(serde::de::Error::custom)?;
+        Ok(control)
+    }
+}
+
+/// Fifo modes selectable with the `fifo_mode` bitfield of [FifoControl].
+///
+/// The discriminant of each variant is its mode code.
+///
+/// [FifoControl]: struct.FifoControl.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum FifoMode {
+    /// FIFO is bypassed.
+    Bypass = 0b00,
+    /// FIFO collects up to 32 values and then stops collecting data.
+    Fifo = 0b01,
+    /// FIFO holds the last 32 data values, when full the oldest data is
+    /// overwritten with newer data.
+    Stream = 0b10,
+    /// When triggered FIFO holds the last data samples before the trigger
+    /// event and then continues to collect data until full.
+    Trigger = 0b11,
+}
+
+impl FifoMode {
+    /// Check if the fifo buffers samples so none are dropped between reads
+    /// at high data rates (any mode except bypass).
+    pub fn is_buffered(&self) -> bool {
+        *self != FifoMode::Bypass
+    }
+}
+
+impl TryFrom<u8> for FifoMode {
+    type Error = AdxlError;
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        use FifoMode::*;
+        Ok(match value {
+            0b00 => Bypass,
+            0b01 => Fifo,
+            0b10 => Stream,
+            0b11 => Trigger,
+            _ => return Err(AdxlError::UnknownModeBit(value)),
+        })
+    }
+}
+
 /// Fifo buffer status bitfields used in [fifo_status()] method.
 ///
 /// [fifo_status()]: trait.Adxl345Reader.html#method.fifo_status
 #[repr(C, align(1))]
-#[derive(BitfieldStruct, Clone, Copy)]
+#[derive(BitfieldStruct, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct FifoStatus {
     /// Bit fields:
     /// * `fifo_trigger` - (Bit 7) Is `true` if trigger event occurred.
     /// * `entries` - (Bits 0-5) Reports how many entries are available in FIFO.
-    /// ___Note:___ _Maximum value is 33 since FIFO can store 32 entries plus
-    /// the one available from the output filter of the device._
+    ///   ___Note:___ _Maximum value is 33 since FIFO can store 32 entries plus
+    ///   the one available from the output filter of the device._
     #[bitfield(name = "fifo_trigger", ty = "bool", bits = "7..=7")]
     #[bitfield(name = "entries", ty = "u8", bits = "0..=5")]
     byte: [u8; 1],
 }
 
+impl FifoStatus {
+    /// Mask of the defined bitfields.
+    ///
+    /// Bit 6 is reserved.
+    const MASK: u8 = 0xbf;
+    /// Lenient constructor which clears any set reserved bits (bit 6) instead
+    /// of failing like `try_from()` does.
+    ///
+    /// ## Arguments
+    /// * `value` - Raw register byte.
+    pub fn from_bits_truncate(value: u8) -> Self {
+        Self {
+            byte: [value & Self::MASK; 1],
+        }
+    }
+}
+
 impl TryFrom<u8> for FifoStatus {
     type Error = AdxlError;
     //noinspection DuplicatedCode
     fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
         // Bit-wise AND with negative mask of allowed bitfields.
-        if value & !0xbf == 0 {
+        if value & !Self::MASK == 0 {
             Ok(Self { byte: [value; 1] })
         } else {
             Err(AdxlError::UnknownModeBit(value))
@@ -903,6 +3282,13 @@ impl TryFrom<u8> for FifoStatus {
     }
 }
 
+impl From<FifoStatus> for u8 {
+    /// Converts back into the raw register byte.
+    fn from(value: FifoStatus) -> Self {
+        value.byte[0]
+    }
+}
+
 // Interrupt control mode.
 bitflags! {
     /// Interrupt enable control bit flags use by [interrupt_control()] and
@@ -911,6 +3297,7 @@ bitflags! {
     /// [interrupt_control()]: trait.Adxl345Reader.html#method.interrupt_control
     /// [set_interrupt_control()]: trait.Adxl345Writer.html#method.set_interrupt_control
     #[derive(Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
     pub struct IntControlMode: u8 {
         /// Disable DATA_READY interrupt.
         ///
@@ -953,6 +3340,22 @@ bitflags! {
     }
 }
 
+impl IntControlMode {
+    /// Iterates over each interrupt enabled as an [IntSourceKind].
+    ///
+    /// [IntSourceKind]: enum.IntSourceKind.html
+    pub fn iter(&self) -> impl Iterator<Item = IntSourceKind> {
+        set_bits(self.bits()).filter_map(IntSourceKind::from_bit)
+    }
+}
+
+impl From<u8> for IntControlMode {
+    /// Converts a raw byte silently dropping (truncating) any undefined bits.
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
 // Interrupt map mode.
 bitflags! {
     /// Interrupt map bit flags use by [interrupt_map()] and [set_interrupt_map()] methods.
@@ -960,6 +3363,7 @@ bitflags! {
     /// [interrupt_map()]: trait.Adxl345Reader.html#method.interrupt_map
     /// [set_interrupt_map()]: trait.Adxl345Writer.html#method.set_interrupt_map
     #[derive(Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
     pub struct IntMapMode: u8 {
         /// Map DATA_READY interrupt to `INT1` pin.
         const DATA_READY_INT1 = 0x00;
@@ -996,12 +3400,39 @@ bitflags! {
     }
 }
 
+impl IntMapMode {
+    /// Iterates over each interrupt mapped to the `INT2` pin as an [IntSourceKind].
+    ///
+    /// [IntSourceKind]: enum.IntSourceKind.html
+    pub fn iter(&self) -> impl Iterator<Item = IntSourceKind> {
+        set_bits(self.bits()).filter_map(IntSourceKind::from_bit)
+    }
+}
+
+impl From<u8> for IntMapMode {
+    /// Converts a raw byte silently dropping (truncating) any undefined bits.
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+/// Interrupt pins an interrupt can be mapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum IntPin {
+    /// INT1 pin, used when the interrupt map bit is cleared.
+    Int1,
+    /// INT2 pin, used when the interrupt map bit is set.
+    Int2,
+}
+
 // Interrupt source.
 bitflags! {
     /// Interrupt source bit flags use by [interrupt_source()] method.
     ///
     /// [interrupt_source()]: trait.Adxl345Reader.html#method.interrupt_source
     #[derive(Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
     pub struct IntSource: u8 {
         /// Function triggered DATA_READY event.
         ///
@@ -1046,26 +3477,266 @@ bitflags! {
     }
 }
 
+impl IntSource {
+    /// Iterates over each interrupt triggered as an [IntSourceKind].
+    ///
+    /// [IntSourceKind]: enum.IntSourceKind.html
+    pub fn iter(&self) -> impl Iterator<Item = IntSourceKind> {
+        set_bits(self.bits()).filter_map(IntSourceKind::from_bit)
+    }
+}
+
+impl From<u8> for IntSource {
+    /// Converts a raw byte silently dropping (truncating) any undefined bits.
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+/// Individual interrupt kinds.
+///
+/// The interrupt enable, map, and source registers share the same bit layout
+/// and the discriminant of each variant is its bit in those registers.
+///
+/// Converts into each of the matching flag types so a single kind can be
+/// passed to [set_interrupt_control()] or [set_interrupt_map()].
+///
+/// [set_interrupt_control()]: trait.Adxl345Writer.html#method.set_interrupt_control
+/// [set_interrupt_map()]: trait.Adxl345Writer.html#method.set_interrupt_map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum IntSourceKind {
+    /// DATA_READY interrupt.
+    DataReady = 0x80,
+    /// SINGLE_TAP interrupt.
+    SingleTap = 0x40,
+    /// DOUBLE_TAP interrupt.
+    DoubleTap = 0x20,
+    /// ACTIVITY interrupt.
+    Activity = 0x10,
+    /// INACTIVITY interrupt.
+    Inactivity = 0x08,
+    /// FREE_FALL interrupt.
+    FreeFall = 0x04,
+    /// WATERMARK interrupt.
+    Watermark = 0x02,
+    /// OVERRUN interrupt.
+    Overrun = 0x01,
+}
+
+impl IntSourceKind {
+    /// All interrupt kinds in register bit order from MSB to LSB.
+    pub const ALL: [IntSourceKind; 8] = [
+        IntSourceKind::DataReady,
+        IntSourceKind::SingleTap,
+        IntSourceKind::DoubleTap,
+        IntSourceKind::Activity,
+        IntSourceKind::Inactivity,
+        IntSourceKind::FreeFall,
+        IntSourceKind::Watermark,
+        IntSourceKind::Overrun,
+    ];
+    /// Access the matching interrupt enable flag.
+    pub fn control_flag(&self) -> IntControlMode {
+        IntControlMode::from_bits_truncate(*self as u8)
+    }
+    /// Converts a single bit mask into its interrupt kind.
+    fn from_bit(bit: u8) -> Option<Self> {
+        Self::ALL.iter().copied().find(|kind| *kind as u8 == bit)
+    }
+    /// Access the matching interrupt map flag which routes the interrupt to
+    /// the INT2 pin when set.
+    pub fn map_flag(&self) -> IntMapMode {
+        IntMapMode::from_bits_truncate(*self as u8)
+    }
+    /// Access the matching interrupt source flag.
+    pub fn source_flag(&self) -> IntSource {
+        IntSource::from_bits_truncate(*self as u8)
+    }
+}
+
+impl From<IntSourceKind> for IntControlMode {
+    fn from(kind: IntSourceKind) -> Self {
+        kind.control_flag()
+    }
+}
+
+impl From<IntSourceKind> for IntMapMode {
+    fn from(kind: IntSourceKind) -> Self {
+        kind.map_flag()
+    }
+}
+
+impl From<IntSourceKind> for IntSource {
+    fn from(kind: IntSourceKind) -> Self {
+        kind.source_flag()
+    }
+}
+
+/// Output data rates selectable with the `rate` bitfield of
+/// [BandwidthRateControl].
+///
+/// The discriminant of each variant is its rate code.
+///
+/// Rates are ordered by frequency so `rate > OutputDataRate::Hz100` means
+/// faster than 100 Hz.
+///
+/// [BandwidthRateControl]: struct.BandwidthRateControl.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum OutputDataRate {
+    /// 0.10 Hz output data rate.
+    Hz0_10 = 0b0000,
+    /// 0.20 Hz output data rate.
+    Hz0_20 = 0b0001,
+    /// 0.39 Hz output data rate.
+    Hz0_39 = 0b0010,
+    /// 0.78 Hz output data rate.
+    Hz0_78 = 0b0011,
+    /// 1.56 Hz output data rate.
+    Hz1_56 = 0b0100,
+    /// 3.13 Hz output data rate.
+    Hz3_13 = 0b0101,
+    /// 6.25 Hz output data rate.
+    Hz6_25 = 0b0110,
+    /// 12.5 Hz output data rate.
+    Hz12_5 = 0b0111,
+    /// 25 Hz output data rate.
+    Hz25 = 0b1000,
+    /// 50 Hz output data rate.
+    Hz50 = 0b1001,
+    /// 100 Hz output data rate.
+    Hz100 = 0b1010,
+    /// 200 Hz output data rate.
+    Hz200 = 0b1011,
+    /// 400 Hz output data rate.
+    Hz400 = 0b1100,
+    /// 800 Hz output data rate.
+    Hz800 = 0b1101,
+    /// 1600 Hz output data rate.
+    Hz1600 = 0b1110,
+    /// 3200 Hz output data rate.
+    Hz3200 = 0b1111,
+}
+
+impl OutputDataRate {
+    /// Access the output bandwidth in Hz which is half of the data rate.
+    pub fn bandwidth_hz(&self) -> f64 {
+        self.hz() / 2.0
+    }
+    /// Access the output data rate in Hz.
+    pub fn hz(&self) -> f64 {
+        3200.0 / (1u16 << (0b1111 - *self as u8)) as f64
+    }
+    /// Access the time between samples at this rate.
+    ///
+    /// Uses only integer math in ns and is exact for every rate.
+    pub fn period(&self) -> Duration {
+        Duration::from_nanos((1u64 << (0b1111 - *self as u8)) * 312_500)
+    }
+    /// Access the time between samples at this rate in whole μs.
+    ///
+    /// Uses only integer math and is exact for every rate except 3200 Hz,
+    /// where the 312.5 μs period is truncated to 312 μs.
+    pub fn period_us(&self) -> u32 {
+        (1u32 << (0b1111 - *self as u8)) * 625 / 2
+    }
+    /// Access the typical supply current in μA at this rate.
+    ///
+    /// Values come from the datasheet current consumption tables at a 2.5 V
+    /// supply.
+    /// `low_power` only changes the result for the 12.5 Hz to 400 Hz rates
+    /// where reduced power operation is supported.
+    ///
+    /// ## Arguments
+    /// * `low_power` - true: reduced power operation; false: normal operation.
+    pub fn supply_current_ua(&self, low_power: bool) -> u32 {
+        use OutputDataRate::*;
+        match (self, low_power && self.supports_low_power()) {
+            (Hz400, true) => 90,
+            (Hz200, true) => 60,
+            (Hz100, true) => 50,
+            (Hz50, true) => 45,
+            (Hz25, true) => 40,
+            (Hz12_5, true) => 34,
+            (Hz3200, _) | (Hz800, _) | (Hz400, _) | (Hz200, _) | (Hz100, _) => 140,
+            (Hz1600, _) | (Hz50, _) => 90,
+            (Hz25, _) => 60,
+            (Hz12_5, _) => 50,
+            (Hz6_25, _) => 45,
+            (Hz3_13, _) => 40,
+            (Hz1_56, _) => 34,
+            (Hz0_78, _) | (Hz0_39, _) | (Hz0_20, _) | (Hz0_10, _) => 23,
+        }
+    }
+    /// Check if the rate is in the 12.5 Hz to 400 Hz range where the
+    /// `low_power` bit gives reduced power operation.
+    pub fn supports_low_power(&self) -> bool {
+        (OutputDataRate::Hz12_5 as u8..=OutputDataRate::Hz400 as u8).contains(&(*self as u8))
+    }
+    /// Access the time needed after changing the data rate (or turning on or
+    /// waking up the device) before the first valid sample.
+    ///
+    /// The datasheet gives this as about 1.1 ms plus one output data period,
+    /// for example 11.1 ms at 100 Hz.
+    pub fn settling_time(&self) -> Duration {
+        Duration::from_micros(1100) + self.period()
+    }
+}
+
+impl TryFrom<u8> for OutputDataRate {
+    type Error = AdxlError;
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        use OutputDataRate::*;
+        Ok(match value {
+            0b0000 => Hz0_10,
+            0b0001 => Hz0_20,
+            0b0010 => Hz0_39,
+            0b0011 => Hz0_78,
+            0b0100 => Hz1_56,
+            0b0101 => Hz3_13,
+            0b0110 => Hz6_25,
+            0b0111 => Hz12_5,
+            0b1000 => Hz25,
+            0b1001 => Hz50,
+            0b1010 => Hz100,
+            0b1011 => Hz200,
+            0b1100 => Hz400,
+            0b1101 => Hz800,
+            0b1110 => Hz1600,
+            0b1111 => Hz3200,
+            _ => return Err(AdxlError::UnknownModeBit(value)),
+        })
+    }
+}
+
 /// Power control bitfields used in [power_control()] and [set_power_control()]
 /// methods.
 ///
 /// [power_control()]: trait.Adxl345Reader.html#method.power_control
 /// [set_power_control()]: trait.Adxl345Writer.html#method.set_power_control
 #[repr(C, align(1))]
-#[derive(BitfieldStruct, Clone, Copy)]
+#[derive(BitfieldStruct, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct PowerControl {
     /// Bit fields:
     /// * `link` - (Bit 5) This bit serially links the activity and inactivity
-    /// functions.
-    /// When the bit is 0 the inactivity and activity functions are concurrent.
+    ///   functions.
+    ///   When the bit is 0 the inactivity and activity functions are concurrent.
     /// * `auto_sleep` - (Bit 4) If the `link` bit is set, a setting of 1 in the
-    /// `auto_sleep` bit enables the auto-sleep functionality.
-    /// When bit is 0 then the activity/inactivity settings are ignored.
+    ///   `auto_sleep` bit enables the auto-sleep functionality.
+    ///   When bit is 0 then the activity/inactivity settings are ignored.
     /// * `measure` - (Bit 3) Standby/measurement mode.
-    /// The ADXL345 is in standby mode at power up.
+    ///   The ADXL345 is in standby mode at power up.
     /// * `sleep` - (Bit 2) Puts the part into sleep mode.
     /// * `wakeup` - (Bits 0-1) Controls the frequency of readings in sleep mode
-    /// as described in table.
+    ///   as described in table.
     ///
     /// ___Note:___ _It is recommended that the `measure` bit be placed into
     /// standby mode and then set back to measurement mode with a subsequent
@@ -1088,12 +3759,70 @@ pub struct PowerControl {
     byte: [u8; 1],
 }
 
+impl PowerControl {
+    /// Mask of the defined bitfields.
+    ///
+    /// Bits 6-7 are reserved.
+    const MASK: u8 = 0x3f;
+    /// Lenient constructor which clears any set reserved bits (bits 6-7) instead
+    /// of failing like `try_from()` does.
+    ///
+    /// ## Arguments
+    /// * `value` - Raw register byte.
+    pub fn from_bits_truncate(value: u8) -> Self {
+        Self {
+            byte: [value & Self::MASK; 1],
+        }
+    }
+    /// Returns a copy with the `auto_sleep` bit changed.
+    ///
+    /// ## Arguments
+    /// * `auto_sleep` - true: auto-sleep enabled; false: disabled.
+    pub fn with_auto_sleep(mut self, auto_sleep: bool) -> Self {
+        self.set_auto_sleep(auto_sleep);
+        self
+    }
+    /// Returns a copy with the `link` bit changed.
+    ///
+    /// ## Arguments
+    /// * `link` - true: activity and inactivity linked; false: concurrent.
+    pub fn with_link(mut self, link: bool) -> Self {
+        self.set_link(link);
+        self
+    }
+    /// Returns a copy with the `measure` bit changed.
+    ///
+    /// ## Arguments
+    /// * `measure` - true: measurement mode; false: standby mode.
+    pub fn with_measure(mut self, measure: bool) -> Self {
+        self.set_measure(measure);
+        self
+    }
+    /// Returns a copy with the `sleep` bit changed.
+    ///
+    /// ## Arguments
+    /// * `sleep` - true: sleep mode; false: normal mode.
+    pub fn with_sleep(mut self, sleep: bool) -> Self {
+        self.set_sleep(sleep);
+        self
+    }
+    /// Returns a copy with the `wakeup` bits changed.
+    ///
+    /// ## Arguments
+    /// * `wakeup` - Reading frequency code in sleep mode (0-3 for 8, 4, 2, or
+    ///   1 Hz). Only the low 2 bits are used.
+    pub fn with_wakeup(mut self, wakeup: u8) -> Self {
+        self.set_wakeup(wakeup & 0x03);
+        self
+    }
+}
+
 impl TryFrom<u8> for PowerControl {
     type Error = AdxlError;
     //noinspection DuplicatedCode
     fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
         // Bit-wise AND with negative mask of allowed bitfields.
-        if value & !0x3f == 0 {
+        if value & !Self::MASK == 0 {
             Ok(Self { byte: [value; 1] })
         } else {
             Err(AdxlError::UnknownModeBit(value))
@@ -1101,13 +3830,64 @@ impl TryFrom<u8> for PowerControl {
     }
 }
 
+impl From<PowerControl> for u8 {
+    /// Converts back into the raw register byte.
+    fn from(value: PowerControl) -> Self {
+        value.byte[0]
+    }
+}
+
+/// Measurement ranges selectable with the `range` bitfield of [DataFormat].
+///
+/// The discriminant of each variant is its range code.
+///
+/// Ranges are ordered by their full scale magnitude so `range >= Range::G8`
+/// means at least ±8 g.
+///
+/// [DataFormat]: struct.DataFormat.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum Range {
+    /// ±2 g range.
+    G2 = 0b00,
+    /// ±4 g range.
+    G4 = 0b01,
+    /// ±8 g range.
+    G8 = 0b10,
+    /// ±16 g range.
+    G16 = 0b11,
+}
+
+impl Range {
+    /// Access the full scale of the range in g.
+    pub fn g(&self) -> f64 {
+        (2u8 << *self as u8) as f64
+    }
+}
+
+impl TryFrom<u8> for Range {
+    type Error = AdxlError;
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        use Range::*;
+        Ok(match value {
+            0b00 => G2,
+            0b01 => G4,
+            0b10 => G8,
+            0b11 => G16,
+            _ => return Err(AdxlError::UnknownModeBit(value)),
+        })
+    }
+}
+
 /// Hold a collection of single/double tap non-control related values.
 ///
 /// Structure is used by the [tap()] and [set_tap()] methods.
 ///
 /// [tap()]: trait.Adxl345Reader.html#method.tap
 /// [set_tap()]: trait.Adxl345Writer.html#method.set_tap
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Tap {
     /// Threshold value required to trigger a tap interrupt.
     ///
@@ -1142,22 +3922,22 @@ impl Tap {
     ///
     /// ## Arguments
     /// * `threshold` - Threshold value required to trigger a tap interrupt.
-    /// The scale factor is 62.5 mg/LSB.
-    /// ___Note:___ _that a value of 0 may result in undesirable behavior if
-    /// the single tap/double tap interrupt(s) are enabled._
+    ///   The scale factor is 62.5 mg/LSB.
+    ///   ___Note:___ _that a value of 0 may result in undesirable behavior if
+    ///   the single tap/double tap interrupt(s) are enabled._
     /// * `duration` - Time value representing the maximum time that an event
-    /// must be above the threshold to qualify as a tap event.
-    /// The scale factor is 625 μs/LSB.
-    /// A value of 0 disables the single tap/double tap functions.
+    ///   must be above the threshold to qualify as a tap event.
+    ///   The scale factor is 625 μs/LSB.
+    ///   A value of 0 disables the single tap/double tap functions.
     /// * `latency` - Time value representing the wait time from the detection
-    /// of a tap event to the start of the time window during which a possible
-    /// second tap event can be detected.
-    /// The scale factor is 1.25 ms/LSB.
-    /// A value of 0 disables the double tap function.
+    ///   of a tap event to the start of the time window during which a possible
+    ///   second tap event can be detected.
+    ///   The scale factor is 1.25 ms/LSB.
+    ///   A value of 0 disables the double tap function.
     /// * `window` - Time value representing the amount of time after the
-    /// expiration of the latency time during which a second valid tap can begin.
-    /// The scale factor is 1.25 ms/LSB.
-    /// A value of 0 disables the double tap function.
+    ///   expiration of the latency time during which a second valid tap can begin.
+    ///   The scale factor is 1.25 ms/LSB.
+    ///   A value of 0 disables the double tap function.
     pub fn new(threshold: u8, duration: u8, latency: u8, window: u8) -> Self {
         Tap {
             threshold,
@@ -1168,6 +3948,21 @@ impl Tap {
     }
 }
 
+impl fmt::Display for Tap {
+    /// Formats the decoded physical values using the scale factor of each
+    /// field.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Tap {{ threshold: {}mg, duration: {:.3}ms, latency: {:.2}ms, window: {:.2}ms }}",
+            self.threshold as f64 * 62.5,
+            self.duration as f64 * 0.625,
+            self.latency as f64 * 1.25,
+            self.window as f64 * 1.25
+        )
+    }
+}
+
 impl From<(u8, u8, u8, u8)> for Tap {
     fn from(tap: (u8, u8, u8, u8)) -> Self {
         Tap {
@@ -1190,6 +3985,58 @@ impl From<[u8; 4]> for Tap {
     }
 }
 
+/// Single/double tap values in physical units which convert into [Tap].
+///
+/// Each value is rounded to the nearest count of its register and the
+/// conversion returns [AdxlError::ValueOutOfRange] when it does not fit.
+///
+/// [Tap]: struct.Tap.html
+/// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TapConfig {
+    /// Threshold required to trigger a tap interrupt in mg (0-15937.5 mg).
+    pub threshold_mg: f64,
+    /// Maximum time an event must be above the threshold to qualify as a tap
+    /// event in ms (0-159.375 ms).
+    pub duration_ms: f64,
+    /// Wait time from the detection of a tap event to the start of the double
+    /// tap window in ms (0-318.75 ms).
+    pub latency_ms: f64,
+    /// Time after the latency during which a second valid tap can begin in ms
+    /// (0-318.75 ms).
+    pub window_ms: f64,
+}
+
+impl TryFrom<TapConfig> for Tap {
+    type Error = AdxlError;
+    fn try_from(config: TapConfig) -> std::result::Result<Self, Self::Error> {
+        Ok(Tap {
+            threshold: checked_count(config.threshold_mg, 62.5)?,
+            duration: checked_count(config.duration_ms, 0.625)?,
+            latency: checked_count(config.latency_ms, 1.25)?,
+            window: checked_count(config.window_ms, 1.25)?,
+        })
+    }
+}
+
+/// Tap event returned by the [poll_tap()] method.
+///
+/// [poll_tap()]: trait.Adxl345Reader.html#method.poll_tap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapEvent {
+    /// A single tap event occurred.
+    Single {
+        /// Axes involved in the tap event (only the `TAP_*` flags are used).
+        axes: ATStatus,
+    },
+    /// A double tap event occurred.
+    Double {
+        /// Axes involved in the tap event (only the `TAP_*` flags are used).
+        axes: ATStatus,
+    },
+}
+
 // Tap Axis control mode.
 bitflags! {
     /// Tap axis mode bit flags used in [tap_control()] and [set_tap_control()]
@@ -1198,6 +4045,7 @@ bitflags! {
     /// [tap_control()]: trait.Adxl345Reader.html#method.tap_control
     /// [set_tap_control()]: trait.Adxl345Writer.html#method.set_tap_control
     #[derive(Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
     pub struct TapMode: u8 {
         /// Disable (suppress) double tap detection if acceleration is greater
         /// than tap threshold between taps.
@@ -1219,3 +4067,182 @@ bitflags! {
         const Z_ENABLE = 0x01;
     }
 }
+
+impl TapMode {
+    /// Check if double tap detection is suppressed by acceleration greater
+    /// than the tap threshold between taps.
+    pub fn double_tap_suppressed(&self) -> bool {
+        self.contains(Self::DT_DISABLE)
+    }
+    /// Check if the X-axis participates in tap detection.
+    pub fn x_enabled(&self) -> bool {
+        self.contains(Self::X_ENABLE)
+    }
+    /// Check if the Y-axis participates in tap detection.
+    pub fn y_enabled(&self) -> bool {
+        self.contains(Self::Y_ENABLE)
+    }
+    /// Check if the Z-axis participates in tap detection.
+    pub fn z_enabled(&self) -> bool {
+        self.contains(Self::Z_ENABLE)
+    }
+    /// Iterates over each set flag as a single flag value.
+    pub fn iter(&self) -> impl Iterator<Item = Self> {
+        set_bits(self.bits()).map(Self::from_bits_truncate)
+    }
+}
+
+impl From<u8> for TapMode {
+    /// Converts a raw byte silently dropping (truncating) any undefined bits.
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks every byte either round-trips or is rejected exactly when a
+    /// reserved bit outside of `mask` is set.
+    ///
+    /// The masks are taken from the datasheet register maps and not from the
+    /// types so a wrong `MASK` is caught.
+    fn check_reserved_bits<T>(mask: u8)
+    where
+        T: TryFrom<u8>,
+        u8: From<T>,
+    {
+        for byte in 0..=255u8 {
+            let parsed = T::try_from(byte);
+            assert_eq!(parsed.is_ok(), byte & !mask == 0, "byte {:#04x}", byte);
+            if let Ok(value) = parsed {
+                assert_eq!(u8::from(value), byte, "byte {:#04x}", byte);
+            }
+        }
+    }
+
+    #[test]
+    fn bandwidth_rate_control_reserved_bits() {
+        // Bits 5-7 are reserved.
+        check_reserved_bits::<BandwidthRateControl>(0x1f);
+    }
+
+    #[test]
+    fn data_format_reserved_bits() {
+        // Bit 4 is reserved.
+        check_reserved_bits::<DataFormat>(0xef);
+    }
+
+    #[test]
+    fn fifo_status_reserved_bits() {
+        // Bit 6 is reserved.
+        check_reserved_bits::<FifoStatus>(0xbf);
+    }
+
+    #[test]
+    fn power_control_reserved_bits() {
+        // Bits 6-7 are reserved.
+        check_reserved_bits::<PowerControl>(0x3f);
+    }
+
+    #[test]
+    fn sign_extend_if_needed_one_g() {
+        // (full_res, range, justify, +1 g raw, -1 g raw) from the datasheet.
+        let rows: [(bool, Range, bool, u16, u16); 7] = [
+            (false, Range::G2, false, 0x0100, 0xff00),
+            (false, Range::G2, true, 0x4000, 0xc000),
+            (false, Range::G16, false, 0x0020, 0xffe0),
+            (false, Range::G16, true, 0x0800, 0xf800),
+            (true, Range::G2, true, 0x4000, 0xc000),
+            (true, Range::G16, false, 0x0100, 0xff00),
+            (true, Range::G16, true, 0x0800, 0xf800),
+        ];
+        for (full_res, range, justify, plus, minus) in rows.iter().copied() {
+            let format = DataFormat::from_bits_truncate(0)
+                .with_full_res(full_res)
+                .with_range(range)
+                .with_justify(justify);
+            for (raw, g) in [(plus, 1.0), (minus, -1.0)] {
+                let value = format.sign_extend_if_needed(raw as i16) as f64 * format.scale_factor();
+                assert!(
+                    (value - g).abs() < 1e-9,
+                    "full_res {} range {:?} justify {} raw {:#06x} gave {} g",
+                    full_res,
+                    range,
+                    justify,
+                    raw,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_sample_is_little_endian() {
+        let bytes = [0xff, 0x01, 0x00, 0xff, 0x01, 0xfe];
+        assert_eq!(parse_sample(&bytes), (511, -256, -511));
+    }
+
+    #[test]
+    fn checked_count_register_maximums() {
+        // (scale, largest value which fits, smallest value which does not)
+        let cases = [
+            // DUR in μs.
+            (625.0, 159_375.0, 159_688.0),
+            // Latent and Window in μs.
+            (1250.0, 318_750.0, 319_375.0),
+            // TIME_FF in μs.
+            (5000.0, 1_275_000.0, 1_277_500.0),
+            // THRESH_TAP, THRESH_ACT, THRESH_INACT, and THRESH_FF in mg.
+            (62.5, 15_937.5, 15_968.75),
+        ];
+        for (scale, fits, too_large) in cases.iter().copied() {
+            assert_eq!(checked_count(fits, scale).unwrap(), 0xff);
+            assert!(
+                matches!(
+                    checked_count(too_large, scale),
+                    Err(AdxlError::ValueOutOfRange { .. })
+                ),
+                "{} at scale {}",
+                too_large,
+                scale
+            );
+            assert_eq!(saturating_count(too_large, scale), 0xff);
+        }
+        assert!(checked_count(-1000.0, 62.5).is_err());
+        assert_eq!(saturating_count(-1000.0, 62.5), 0);
+        assert_eq!(saturating_count(20_000.0, 62.5), 0xff);
+    }
+
+    #[test]
+    fn data_format_reserved_bit_4_is_unknown_mode_bit() {
+        assert!(matches!(
+            DataFormat::try_from(0x10),
+            Err(AdxlError::UnknownModeBit(_))
+        ));
+    }
+
+    #[test]
+    fn period_is_exact_at_3200_hz() {
+        let rate = OutputDataRate::Hz3200;
+        assert_eq!(rate.period(), Duration::from_nanos(312_500));
+        assert_eq!(rate.period_us(), 312);
+        assert_eq!(rate.settling_time(), Duration::from_nanos(1_412_500));
+        assert_eq!(OutputDataRate::Hz100.period(), Duration::from_millis(10));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_rejects_invalid_register_bytes() {
+        use serde::{de::IntoDeserializer, Deserialize};
+        type Error = serde::de::value::Error;
+        let byte = |value: u8| IntoDeserializer::<Error>::into_deserializer(value);
+        assert!(DataFormat::deserialize(byte(0x10)).is_err());
+        assert!(PowerControl::deserialize(byte(0x40)).is_err());
+        // Trigger mode with 0 samples.
+        assert!(FifoControl::deserialize(byte(0xc0)).is_err());
+        let control = FifoControl::deserialize(byte(0xc4)).unwrap();
+        assert_eq!(u8::from(control), 0xc4);
+    }
+}
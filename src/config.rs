@@ -0,0 +1,259 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains a complete device configuration (profile) type.
+//!
+//! With the `serde` feature enabled the configuration can be serialized to and
+//! deserialized from any serde supported format which makes it usable as a
+//! loadable profile file.
+//! The register structures are stored as their raw bytes and a profile with
+//! reserved bits set or a hazardous fifo configuration is rejected when it is
+//! deserialized instead of later by [Adxl345Config::apply()].
+//!
+//! [Adxl345Config::apply()]: struct.Adxl345Config.html#method.apply
+
+use crate::{
+    ActivityMode, Adxl345Writer, AdxlError, BandwidthRateControl, DataFormat, FifoControl,
+    IntControlMode, IntMapMode, PowerControl, Result, Tap, TapMode,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// Holds the complete desired state of all the writable configuration
+/// registers.
+///
+/// Use [apply()] to write the whole configuration to a device in a datasheet
+/// safe order.
+///
+/// The [Default] configuration matches the register values written during
+/// device initialization.
+///
+/// [apply()]: struct.Adxl345Config.html#method.apply
+/// [Default]: struct.Adxl345Config.html#impl-Default
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Adxl345Config {
+    /// Activity/inactivity control mode (ACT_INACT_CTL).
+    pub activity_control: ActivityMode,
+    /// Activity threshold (THRESH_ACT).
+    ///
+    /// The scale factor is 62.5 mg/LSB.
+    pub activity_threshold: u8,
+    /// Data rate and power mode control (BW_RATE).
+    pub bandwidth_rate: BandwidthRateControl,
+    /// Data format control (DATA_FORMAT).
+    ///
+    /// ___Note:___ _When using 3-wire SPI the `spi` bit must be kept set or
+    /// communication with the device will be lost._
+    pub data_format: DataFormat,
+    /// Fifo control (FIFO_CTL).
+    pub fifo_control: FifoControl,
+    /// Free-fall threshold (THRESH_FF).
+    ///
+    /// The scale factor is 62.5 mg/LSB.
+    pub free_fall_threshold: u8,
+    /// Free-fall time (TIME_FF).
+    ///
+    /// The scale factor is 5 ms/LSB.
+    pub free_fall_time: u8,
+    /// Inactivity threshold (THRESH_INACT).
+    ///
+    /// The scale factor is 62.5 mg/LSB.
+    pub inactivity_threshold: u8,
+    /// Inactivity time (TIME_INACT).
+    ///
+    /// The scale factor is 1 sec/LSB.
+    pub inactivity_time: u8,
+    /// Interrupt enable control (INT_ENABLE).
+    pub interrupt_control: IntControlMode,
+    /// Interrupt mapping (INT_MAP).
+    pub interrupt_map: IntMapMode,
+    /// X, Y, and Z-axis offset adjustments (OFSX, OFSY, OFSZ).
+    ///
+    /// The scale factor is 15.6 mg/LSB.
+    pub offset_adjustment: (i8, i8, i8),
+    /// Power-saving features control (POWER_CTL).
+    pub power_control: PowerControl,
+    /// Non-control tap values (THRESH_TAP, DUR, Latent, Window).
+    pub tap: Tap,
+    /// Tap axis control mode (TAP_AXES).
+    pub tap_control: TapMode,
+}
+
+impl Adxl345Config {
+    /// First register of the block accepted by `try_from()` (THRESH_TAP).
+    pub const BLOCK_START: u8 = 0x1d;
+    /// Number of registers in the block accepted by `try_from()` which covers
+    /// THRESH_TAP (0x1d) through FIFO_STATUS (0x39).
+    pub const BLOCK_LEN: usize = 29;
+    /// Known-good starting configuration for general use.
+    ///
+    /// * Measurement mode on.
+    /// * ±2 g range with full resolution.
+    /// * 100 Hz output data rate in normal power mode.
+    /// * Fifo bypassed.
+    /// * No interrupts enabled.
+    ///
+    /// Everything else is left at the [Default] values.
+    /// Unlike the [Default] configuration, which matches what device
+    /// initialization writes and leaves the device in standby, this starts
+    /// measuring as soon as it is applied with [apply()].
+    ///
+    /// ___Note:___ _When using 3-wire SPI the `spi` bit of `data_format` must
+    /// be set before applying it._
+    ///
+    /// [apply()]: struct.Adxl345Config.html#method.apply
+    /// [Default]: struct.Adxl345Config.html#impl-Default
+    pub fn sensible_default() -> Self {
+        let mut data_format = DataFormat::from_bits_truncate(0);
+        data_format.set_full_res(true);
+        let mut power_control = PowerControl::from_bits_truncate(0);
+        power_control.set_measure(true);
+        Adxl345Config {
+            data_format,
+            power_control,
+            ..Self::default()
+        }
+    }
+    /// Writes the complete configuration to the device.
+    ///
+    /// The registers are written in the following order:
+    ///
+    /// 1. POWER_CTL with the `measure` bit cleared to place the device into
+    ///    standby mode.
+    /// 2. BW_RATE and DATA_FORMAT.
+    /// 3. Offsets, tap, activity/inactivity, and free-fall registers.
+    /// 4. FIFO_CTL.
+    /// 5. INT_MAP and then INT_ENABLE so no interrupt is enabled while still
+    ///    mapped to the wrong pin.
+    /// 6. POWER_CTL with the final value which restores measurement mode when
+    ///    requested.
+    ///
+    /// The configuration is checked with [validate()] first so a hazardous
+    /// fifo configuration is rejected before anything is written, instead of
+    /// leaving the device half configured in standby mode.
+    ///
+    /// ## Arguments
+    /// * `dev` - Device the configuration is written to.
+    ///
+    /// [validate()]: struct.Adxl345Config.html#method.validate
+    pub fn apply(&self, dev: &mut impl Adxl345Writer) -> Result {
+        self.validate()?;
+        let mut standby = self.power_control;
+        standby.set_measure(false);
+        dev.set_power_control(standby)?;
+        dev.set_bandwidth_rate(self.bandwidth_rate)?;
+        dev.set_data_format(self.data_format)?;
+        let (x, y, z) = self.offset_adjustment;
+        dev.set_offset_adjustment(x, y, z)?;
+        dev.set_tap(self.tap)?;
+        dev.set_tap_control(self.tap_control)?;
+        dev.set_activity_threshold(self.activity_threshold)?;
+        dev.set_inactivity(self.inactivity_threshold, self.inactivity_time)?;
+        dev.set_activity_control(self.activity_control)?;
+        dev.set_free_fall(self.free_fall_threshold, self.free_fall_time)?;
+        dev.set_fifo_control(self.fifo_control)?;
+        dev.set_interrupt_map(self.interrupt_map)?;
+        dev.set_interrupt_control(self.interrupt_control)?;
+        dev.set_power_control(self.power_control)
+    }
+    /// Checks the configuration for values the setters used by [apply()]
+    /// reject.
+    ///
+    /// Returns [AdxlError::InvalidFifoConfig] when `fifo_control` combines
+    /// trigger mode with a `samples` value of 0 or has a `samples` (watermark)
+    /// value in bypass mode.
+    ///
+    /// [apply()]: struct.Adxl345Config.html#method.apply
+    /// [AdxlError::InvalidFifoConfig]: enum.AdxlError.html#variant.InvalidFifoConfig
+    pub fn validate(&self) -> Result {
+        self.fifo_control.validate()
+    }
+}
+
+impl Default for Adxl345Config {
+    fn default() -> Self {
+        Adxl345Config {
+            activity_control: ActivityMode::default(),
+            activity_threshold: 0,
+            bandwidth_rate: BandwidthRateControl::from_bits_truncate(0x0a),
+            data_format: DataFormat::from_bits_truncate(0),
+            fifo_control: FifoControl::new_bypass(),
+            free_fall_threshold: 0,
+            free_fall_time: 0,
+            inactivity_threshold: 0,
+            inactivity_time: 0,
+            interrupt_control: IntControlMode::default(),
+            interrupt_map: IntMapMode::default(),
+            offset_adjustment: (0, 0, 0),
+            power_control: PowerControl::from_bits_truncate(0),
+            tap: Tap::new(0, 0, 0, 0),
+            tap_control: TapMode::default(),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Adxl345Config {
+    type Error = AdxlError;
+    /// Decodes a block of register bytes read from [BLOCK_START] for
+    /// [BLOCK_LEN] registers.
+    ///
+    /// The read-only status and data registers inside the block are ignored.
+    ///
+    /// Returns [AdxlError::InvalidBlockLength] if the block length is wrong and
+    /// [AdxlError::InvalidRegisterValue] with the offending register if any
+    /// byte has undefined bits set.
+    ///
+    /// [BLOCK_START]: struct.Adxl345Config.html#associatedconstant.BLOCK_START
+    /// [BLOCK_LEN]: struct.Adxl345Config.html#associatedconstant.BLOCK_LEN
+    /// [AdxlError::InvalidBlockLength]: enum.AdxlError.html#variant.InvalidBlockLength
+    /// [AdxlError::InvalidRegisterValue]: enum.AdxlError.html#variant.InvalidRegisterValue
+    fn try_from(bytes: &[u8]) -> std::result::Result<Self, Self::Error> {
+        if bytes.len() != Self::BLOCK_LEN {
+            return Err(AdxlError::InvalidBlockLength {
+                expected: Self::BLOCK_LEN,
+                actual: bytes.len(),
+            });
+        }
+        let byte = |register: u8| bytes[(register - Self::BLOCK_START) as usize];
+        let invalid = |register: u8| AdxlError::InvalidRegisterValue {
+            register,
+            value: byte(register),
+        };
+        Ok(Adxl345Config {
+            activity_control: ActivityMode::from_bits(byte(0x27)).ok_or_else(|| invalid(0x27))?,
+            activity_threshold: byte(0x24),
+            bandwidth_rate: byte(0x2c).try_into().map_err(|_| invalid(0x2c))?,
+            data_format: byte(0x31).try_into().map_err(|_| invalid(0x31))?,
+            fifo_control: byte(0x38).into(),
+            free_fall_threshold: byte(0x28),
+            free_fall_time: byte(0x29),
+            inactivity_threshold: byte(0x25),
+            inactivity_time: byte(0x26),
+            interrupt_control: IntControlMode::from_bits(byte(0x2e))
+                .ok_or_else(|| invalid(0x2e))?,
+            interrupt_map: IntMapMode::from_bits(byte(0x2f)).ok_or_else(|| invalid(0x2f))?,
+            offset_adjustment: (byte(0x1e) as i8, byte(0x1f) as i8, byte(0x20) as i8),
+            power_control: byte(0x2d).try_into().map_err(|_| invalid(0x2d))?,
+            tap: Tap::new(byte(0x1d), byte(0x21), byte(0x22), byte(0x23)),
+            tap_control: TapMode::from_bits(byte(0x2a)).ok_or_else(|| invalid(0x2a))?,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains the CSV logging of acceleration samples.
+
+use crate::{cmd::sample_to_g, Adxl345Reader, Result};
+use std::{
+    io::Write,
+    sync::{atomic::AtomicBool, Arc},
+    time::{Duration, Instant},
+};
+
+/// Time between flushes of the writer while logging.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Units of the acceleration values written by [log_csv()].
+///
+/// [log_csv()]: trait.Adxl345Reader.html#method.log_csv
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CsvUnits {
+    /// Raw counts as returned by [acceleration()].
+    ///
+    /// [acceleration()]: trait.Adxl345Reader.html#tymethod.acceleration
+    Counts,
+    /// Acceleration scaled to g using the data format read when logging
+    /// starts.
+    G,
+}
+
+/// Writes paced samples as CSV rows until `running` is cleared.
+///
+/// See [log_csv()] for more information.
+///
+/// [log_csv()]: trait.Adxl345Reader.html#method.log_csv
+pub(crate) fn log_csv<R, W>(
+    reader: &R,
+    mut writer: W,
+    period: Duration,
+    running: Arc<AtomicBool>,
+    units: CsvUnits,
+) -> Result
+where
+    R: Adxl345Reader + ?Sized,
+    W: Write,
+{
+    let format = reader.data_format()?;
+    writeln!(writer, "timestamp,x,y,z")?;
+    let start = Instant::now();
+    let mut flushed = start;
+    for sample in reader.samples_until(running, period) {
+        let (x, y, z) = sample?;
+        let timestamp = start.elapsed().as_secs_f64();
+        match units {
+            CsvUnits::Counts => writeln!(writer, "{:.6},{},{},{}", timestamp, x, y, z)?,
+            CsvUnits::G => {
+                let (x, y, z) = sample_to_g((x, y, z), format);
+                writeln!(writer, "{:.6},{:.4},{:.4},{:.4}", timestamp, x, y, z)?
+            }
+        }
+        if flushed.elapsed() >= FLUSH_INTERVAL {
+            writer.flush()?;
+            flushed = Instant::now();
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
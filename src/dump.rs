@@ -0,0 +1,279 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains the register names and a snapshot (dump) of all the registers for
+//! debugging.
+
+use crate::{Adxl345Config, AdxlError, AdxlResult};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+/// Named device registers.
+///
+/// The discriminant of each variant is its register address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum Register {
+    /// Device ID (DEVID).
+    DevId = 0x00,
+    /// Tap threshold (THRESH_TAP).
+    ThreshTap = 0x1d,
+    /// X-axis offset (OFSX).
+    OfsX = 0x1e,
+    /// Y-axis offset (OFSY).
+    OfsY = 0x1f,
+    /// Z-axis offset (OFSZ).
+    OfsZ = 0x20,
+    /// Tap duration (DUR).
+    Dur = 0x21,
+    /// Tap latency (Latent).
+    Latent = 0x22,
+    /// Tap window (Window).
+    Window = 0x23,
+    /// Activity threshold (THRESH_ACT).
+    ThreshAct = 0x24,
+    /// Inactivity threshold (THRESH_INACT).
+    ThreshInact = 0x25,
+    /// Inactivity time (TIME_INACT).
+    TimeInact = 0x26,
+    /// Axis enable control for activity and inactivity detection
+    /// (ACT_INACT_CTL).
+    ActInactCtl = 0x27,
+    /// Free-fall threshold (THRESH_FF).
+    ThreshFf = 0x28,
+    /// Free-fall time (TIME_FF).
+    TimeFf = 0x29,
+    /// Axis control for single tap/double tap (TAP_AXES).
+    TapAxes = 0x2a,
+    /// Source of single tap/double tap (ACT_TAP_STATUS).
+    ActTapStatus = 0x2b,
+    /// Data rate and power mode control (BW_RATE).
+    BwRate = 0x2c,
+    /// Power-saving features control (POWER_CTL).
+    PowerCtl = 0x2d,
+    /// Interrupt enable control (INT_ENABLE).
+    IntEnable = 0x2e,
+    /// Interrupt mapping control (INT_MAP).
+    IntMap = 0x2f,
+    /// Source of interrupts (INT_SOURCE).
+    IntSource = 0x30,
+    /// Data format control (DATA_FORMAT).
+    DataFormat = 0x31,
+    /// X-axis data 0 (DATAX0).
+    DataX0 = 0x32,
+    /// X-axis data 1 (DATAX1).
+    DataX1 = 0x33,
+    /// Y-axis data 0 (DATAY0).
+    DataY0 = 0x34,
+    /// Y-axis data 1 (DATAY1).
+    DataY1 = 0x35,
+    /// Z-axis data 0 (DATAZ0).
+    DataZ0 = 0x36,
+    /// Z-axis data 1 (DATAZ1).
+    DataZ1 = 0x37,
+    /// FIFO control (FIFO_CTL).
+    FifoCtl = 0x38,
+    /// FIFO status (FIFO_STATUS).
+    FifoStatus = 0x39,
+}
+
+impl Register {
+    /// All named registers in address order.
+    pub const ALL: [Register; 30] = [
+        Register::DevId,
+        Register::ThreshTap,
+        Register::OfsX,
+        Register::OfsY,
+        Register::OfsZ,
+        Register::Dur,
+        Register::Latent,
+        Register::Window,
+        Register::ThreshAct,
+        Register::ThreshInact,
+        Register::TimeInact,
+        Register::ActInactCtl,
+        Register::ThreshFf,
+        Register::TimeFf,
+        Register::TapAxes,
+        Register::ActTapStatus,
+        Register::BwRate,
+        Register::PowerCtl,
+        Register::IntEnable,
+        Register::IntMap,
+        Register::IntSource,
+        Register::DataFormat,
+        Register::DataX0,
+        Register::DataX1,
+        Register::DataY0,
+        Register::DataY1,
+        Register::DataZ0,
+        Register::DataZ1,
+        Register::FifoCtl,
+        Register::FifoStatus,
+    ];
+    /// Access the register address.
+    pub fn address(&self) -> u8 {
+        *self as u8
+    }
+    /// Access the register name as used in the datasheet.
+    pub fn name(&self) -> &'static str {
+        use Register::*;
+        match self {
+            DevId => "DEVID",
+            ThreshTap => "THRESH_TAP",
+            OfsX => "OFSX",
+            OfsY => "OFSY",
+            OfsZ => "OFSZ",
+            Dur => "DUR",
+            Latent => "Latent",
+            Window => "Window",
+            ThreshAct => "THRESH_ACT",
+            ThreshInact => "THRESH_INACT",
+            TimeInact => "TIME_INACT",
+            ActInactCtl => "ACT_INACT_CTL",
+            ThreshFf => "THRESH_FF",
+            TimeFf => "TIME_FF",
+            TapAxes => "TAP_AXES",
+            ActTapStatus => "ACT_TAP_STATUS",
+            BwRate => "BW_RATE",
+            PowerCtl => "POWER_CTL",
+            IntEnable => "INT_ENABLE",
+            IntMap => "INT_MAP",
+            IntSource => "INT_SOURCE",
+            DataFormat => "DATA_FORMAT",
+            DataX0 => "DATAX0",
+            DataX1 => "DATAX1",
+            DataY0 => "DATAY0",
+            DataY1 => "DATAY1",
+            DataZ0 => "DATAZ0",
+            DataZ1 => "DATAZ1",
+            FifoCtl => "FIFO_CTL",
+            FifoStatus => "FIFO_STATUS",
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl TryFrom<u8> for Register {
+    type Error = AdxlError;
+    /// Converts a register address returning [AdxlError::UnknownRegister]
+    /// for reserved or unknown addresses.
+    ///
+    /// [AdxlError::UnknownRegister]: enum.AdxlError.html#variant.UnknownRegister
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|register| *register as u8 == value)
+            .ok_or(AdxlError::UnknownRegister(value))
+    }
+}
+
+/// Snapshot of the values of all the named registers.
+///
+/// Created by the [register_dump()] method.
+///
+/// [register_dump()]: trait.Adxl345Reader.html#method.register_dump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RegisterDump {
+    /// Register values in the same order as [Register::ALL].
+    ///
+    /// [Register::ALL]: enum.Register.html#associatedconstant.ALL
+    values: [u8; 30],
+}
+
+impl RegisterDump {
+    /// Constructor from the device ID and the block of register bytes from
+    /// THRESH_TAP (0x1d) through FIFO_STATUS (0x39).
+    pub(crate) fn new(device_id: u8, block: &[u8]) -> AdxlResult<Self> {
+        if block.len() != Adxl345Config::BLOCK_LEN {
+            return Err(AdxlError::InvalidBlockLength {
+                expected: Adxl345Config::BLOCK_LEN,
+                actual: block.len(),
+            });
+        }
+        let mut values = [0u8; 30];
+        values[0] = device_id;
+        values[1..].copy_from_slice(block);
+        Ok(RegisterDump { values })
+    }
+    /// Decodes the configuration registers of the dump.
+    pub fn config(&self) -> AdxlResult<Adxl345Config> {
+        self.values[1..].try_into()
+    }
+    /// Compares two dumps.
+    ///
+    /// Returns each register whose value differs together with its value in
+    /// this dump followed by its value in `other`.
+    ///
+    /// ## Arguments
+    /// * `other` - Dump to compare against, typically a later one.
+    pub fn diff(&self, other: &RegisterDump) -> Vec<(Register, u8, u8)> {
+        self.iter()
+            .zip(other.iter())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((register, old), (_, new))| (register, old, new))
+            .collect()
+    }
+    /// Access the value of a register.
+    ///
+    /// ## Arguments
+    /// * `register` - Register to be accessed.
+    pub fn get(&self, register: Register) -> u8 {
+        let index = Register::ALL
+            .iter()
+            .position(|r| *r == register)
+            .unwrap_or_default();
+        self.values[index]
+    }
+    /// Iterates over each register together with its value in address order.
+    pub fn iter(&self) -> impl Iterator<Item = (Register, u8)> + '_ {
+        Register::ALL
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+    }
+}
+
+impl fmt::Display for RegisterDump {
+    /// Formats one `NAME (address) = value` line per register.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (register, value) in self.iter() {
+            writeln!(
+                f,
+                "{:<14} ({:#04x}) = {:#04x}",
+                register,
+                register.address(),
+                value
+            )?;
+        }
+        Ok(())
+    }
+}
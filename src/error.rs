@@ -21,11 +21,22 @@
 // SOFTWARE.
 //! A common set of error and result type used in the library.
 
+use std::convert::Infallible;
 use thiserror::Error;
 
 /// Provides a shared set of error types.
 #[derive(Error, Debug)]
 pub enum AdxlError {
+    /// Used when no device responds at the given bus address.
+    #[error("No device found at address {address:#04x}")]
+    DeviceNotFound {
+        /// Bus address which was probed.
+        address: u16,
+    },
+    /// Used when a requested configuration is known to cause undesirable
+    /// device behavior.
+    #[error("Hazardous configuration: {0}")]
+    HazardousConfig(&'static str),
     /// Used when given address (offset) is read-only, reserved, or unknown.
     #[error("Attempted illegal write to address {0}")]
     IllegalWriteAddress(u8),
@@ -35,12 +46,125 @@ pub enum AdxlError {
     /// Used to pass through any underlying SPI errors.
     #[error("SPI interface access failed")]
     Spi(#[from] rppal::spi::Error),
+    /// Used to pass through any I/O errors, for example while logging
+    /// samples.
+    #[error("I/O operation failed")]
+    Io(#[from] std::io::Error),
+    /// Used when the SPI transfer read and write buffer lengths differ.
+    #[error("SPI transfer buffer length mismatch: read {read} bytes, write {write} bytes")]
+    SpiBufferMismatch {
+        /// Length of the read buffer.
+        read: usize,
+        /// Length of the write buffer.
+        write: usize,
+    },
+    /// Used when the device ID read on an SPI bus is not the ADXL345 device
+    /// ID, which is how a missing chip shows up as its data line reads back
+    /// all zero or one bits.
+    #[error(
+        "No device found on SPI bus {bus} slave-select {slave_select}, read device ID {found:#04x}"
+    )]
+    SpiDeviceNotFound {
+        /// SPI bus index which was probed.
+        bus: u8,
+        /// SPI slave-select index which was probed.
+        slave_select: u8,
+        /// Device ID which was read.
+        found: u8,
+    },
+    /// Used when a FIFO `samples` value is out of range or not allowed in the
+    /// selected FIFO mode, like 0 in trigger mode or a watermark in bypass
+    /// mode.
+    #[error("Invalid FIFO configuration with samples value: {0}")]
+    InvalidFifoConfig(u8),
+    /// Used when the requested SPI clock speed is above the device or bus
+    /// maximum.
+    #[error(
+        "SPI clock speed {clock_speed} Hz exceeds the {max} Hz maximum{}",
+        .bus.map(|bus| format!(" of bus {}", bus)).unwrap_or_default()
+    )]
+    InvalidClockSpeed {
+        /// SPI bus index the clock speed was checked for, `None` when the bus
+        /// was opened by the caller.
+        bus: Option<u8>,
+        /// Requested clock speed in Hz.
+        clock_speed: u32,
+        /// Maximum allowed clock speed in Hz.
+        max: u32,
+    },
     /// Invalid bus parameters.
+    ///
+    /// A clock speed above the SPI maximum is reported as
+    /// [AdxlError::InvalidClockSpeed] instead so the limit can be named.
+    ///
+    /// [AdxlError::InvalidClockSpeed]: enum.AdxlError.html#variant.InvalidClockSpeed
     #[error("Invalid bus parameters")]
     InvalidBusParams,
+    /// Used when a sample rate is not a positive finite number or its period
+    /// can not be represented as a non-zero duration.
+    #[error("Invalid sample rate {0} Hz")]
+    InvalidRate(f64),
+    /// Used when a block of register bytes has the wrong length.
+    #[error("Invalid register block length {actual}, expected {expected}")]
+    InvalidBlockLength {
+        /// Number of bytes expected.
+        expected: usize,
+        /// Number of bytes given.
+        actual: usize,
+    },
+    /// Used when a byte from a block of registers can't be decoded.
+    #[error("Invalid value {value:#04x} for register {register:#04x}")]
+    InvalidRegisterValue {
+        /// Register address of the invalid byte.
+        register: u8,
+        /// Invalid byte.
+        value: u8,
+    },
+    /// Used when a physical value can't be represented in its register.
+    #[error("Requested value {requested} is out of range, maximum is {max}")]
+    ValueOutOfRange {
+        /// Requested physical value.
+        requested: f64,
+        /// Largest physical value the register can hold.
+        max: f64,
+    },
+    /// Used when the device did not reach the expected state in time.
+    #[error("Timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    /// Used when a device responds at the given bus address but its device ID
+    /// is not the ADXL345 device ID.
+    #[error("Unexpected device ID {found:#04x} at address {address:#04x}")]
+    UnexpectedDeviceId {
+        /// Bus address which was probed.
+        address: u16,
+        /// Device ID which was read.
+        found: u8,
+    },
+    /// Used when an address (offset) is reserved or past the last register so
+    /// it has no register name.
+    #[error("Unknown register address {0:#04x}")]
+    UnknownRegister(u8),
     /// Used when given an un-excepted value for a mode.
     #[error("Received one or more set unknown mode bit(s) in value: {0}")]
     UnknownModeBit(u8),
+    /// Used when a register read back after a write does not match.
+    #[error("Register {register:#04x} read back {read:#04x} after writing {wrote:#04x}")]
+    VerifyMismatch {
+        /// Register address written.
+        register: u8,
+        /// Byte written into the register.
+        wrote: u8,
+        /// Byte read back from the register.
+        read: u8,
+    },
+}
+
+impl From<Infallible> for AdxlError {
+    /// Allows the typed register structures to be passed directly to setters
+    /// which also accept raw bytes through `TryInto`.
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
 }
 
 /// Result type used when return value is needed from methods in library.
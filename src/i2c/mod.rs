@@ -21,17 +21,37 @@
 // SOFTWARE.
 //! Contains the I²C driver for the device.
 
+mod mux;
+
 use rppal::i2c::I2c;
 
-use crate::{Adxl345, Adxl345Init, Adxl345Reader, Adxl345Writer, AdxlResult, Result};
+pub use self::mux::I2cMux;
+
+use crate::{
+    cmd::check_range,
+    transport::{self, Transport},
+    Adxl345, Adxl345Init, Adxl345Reader, Adxl345Writer, AdxlError, AdxlResult, Result, DEVICE_ID,
+};
+
+/// Checks the slave address is one of the two the device responds to.
+fn check_address(slave: u16) -> Result {
+    match slave {
+        0x53 | 0x1d => Ok(()),
+        _ => Err(AdxlError::InvalidBusParams),
+    }
+}
 
 /// I²C driver structure for the device.
 #[derive(Debug)]
 pub struct Device {
-    /// Holds the bus interface from the [RPPAL I²C] peripheral.
+    /// Holds the generic driver using the bus interface from the
+    /// [RPPAL I²C] peripheral as its transport.
     ///
     /// [RPPAL I²C]: https://docs.golemparts.com/rppal/0.11.3/rppal/i2c/index.html
-    bus: I2c,
+    inner: transport::Device<I2c>,
+    /// Slave address set on the bus, `None` when the bus was opened by the
+    /// caller.
+    address: Option<u16>,
 }
 
 impl Device {
@@ -39,19 +59,248 @@ impl Device {
     pub fn new() -> AdxlResult<Self> {
         Self::with_address(0x53)
     }
+    /// Constructor from an already open [RPPAL I²C] bus.
+    ///
+    /// Useful when the bus is managed elsewhere and shared with other devices.
+    /// The slave address of `bus` must already be set to the device as it is
+    /// used as is and only [init()] is run on it.
+    ///
+    /// ## Arguments
+    /// * `bus` - Open I²C bus with the slave address set.
+    ///
+    /// [RPPAL I²C]: https://docs.golemparts.com/rppal/0.11.3/rppal/i2c/index.html
+    /// [init()]: ../trait.Adxl345Writer.html#tymethod.init
+    pub fn from_i2c(bus: I2c) -> AdxlResult<Self> {
+        log_debug!("Creating I²C device from an open bus {}", bus.bus());
+        let mut device = Device {
+            inner: transport::Device::new_uninit(bus),
+            address: None,
+        };
+        device.init()?;
+        Ok(device)
+    }
     /// Constructor with slave address.
     ///
     /// The device only has two addresses 0x53 or 0x1d depending on the low or
     /// high logic level on the `ALT ADDRESS` pin.
     ///
     /// ## Arguments
-    /// * `slave` - Address of ADXL345 device.
+    /// * `slave` - Address of ADXL345 device. Must be 0x53 or 0x1d else
+    ///   [AdxlError::InvalidBusParams] is returned.
+    ///
+    /// [AdxlError::InvalidBusParams]: ../enum.AdxlError.html#variant.InvalidBusParams
     pub fn with_address(slave: u16) -> AdxlResult<Self> {
-        let mut device = Device { bus: I2c::new()? };
-        device.bus.set_slave_address(slave)?;
+        check_address(slave)?;
+        Self::with_address_unchecked(slave)
+    }
+    /// Constructor with slave address which is not checked against the two
+    /// addresses the device supports.
+    ///
+    /// Useful behind an I²C multiplexer or address translator or with clones
+    /// of the device which use other addresses.
+    ///
+    /// ## Arguments
+    /// * `slave` - Address of ADXL345 device.
+    pub fn with_address_unchecked(slave: u16) -> AdxlResult<Self> {
+        Self::with_i2c(I2c::new()?, slave)
+    }
+    /// Constructor with bus index and slave address.
+    ///
+    /// Useful on newer Pi models which can expose several I²C buses.
+    ///
+    /// | Bus | Availability                                          |
+    /// | --: | ----------------------------------------------------- |
+    /// |   0 | All models (primary bus on the early model B Rev 1)   |
+    /// |   1 | All models (primary bus on physical pins 3 and 5)     |
+    /// | 3-6 | Raspberry Pi 4 when enabled with device tree overlays |
+    ///
+    /// Bus 2 is used internally for HDMI and is rejected.
+    ///
+    /// ## Arguments
+    /// * `bus` - I²C bus index (0, 1, or 3-6).
+    /// * `slave` - Address of ADXL345 device. Must be 0x53 or 0x1d.
+    pub fn with_bus_and_address(bus: u8, slave: u16) -> AdxlResult<Self> {
+        check_address(slave)?;
+        let bus = match bus {
+            0 | 1 | 3 | 4 | 5 | 6 => bus,
+            _ => return Err(AdxlError::InvalidBusParams),
+        };
+        Self::with_i2c(I2c::with_bus(bus)?, slave)
+    }
+    /// Constructor with the default slave address which leaves the device
+    /// configuration untouched.
+    ///
+    /// See [with_address_preserving()] for more information.
+    ///
+    /// [with_address_preserving()]: struct.Device.html#method.with_address_preserving
+    pub fn new_preserving() -> AdxlResult<Self> {
+        Self::with_address_preserving(0x53)
+    }
+    /// Constructor with slave address which leaves the device configuration
+    /// untouched.
+    ///
+    /// Useful when attaching to a device already configured, for example by a
+    /// bootloader, where [with_address()] would reset all the registers.
+    /// The device is probed but nothing is written to it.
+    ///
+    /// ## Arguments
+    /// * `slave` - Address of ADXL345 device. Must be 0x53 or 0x1d else
+    ///   [AdxlError::InvalidBusParams] is returned.
+    ///
+    /// [with_address()]: struct.Device.html#method.with_address
+    /// [AdxlError::InvalidBusParams]: ../enum.AdxlError.html#variant.InvalidBusParams
+    pub fn with_address_preserving(slave: u16) -> AdxlResult<Self> {
+        check_address(slave)?;
+        log_debug!("Attaching to I²C device at address {:#04x}", slave);
+        let mut bus = I2c::new()?;
+        bus.set_slave_address(slave)?;
+        let device = Device {
+            inner: transport::Device::new_uninit(bus),
+            address: Some(slave),
+        };
+        device.probe(slave)?;
+        Ok(device)
+    }
+    fn with_i2c(mut bus: I2c, slave: u16) -> AdxlResult<Self> {
+        log_debug!("Creating I²C device at address {:#04x}", slave);
+        bus.set_slave_address(slave)?;
+        let mut device = Device {
+            inner: transport::Device::new_uninit(bus),
+            address: Some(slave),
+        };
+        device.probe(slave)?;
         device.init()?;
         Ok(device)
     }
+    /// Re-initializes the device registers and then reads each one back.
+    ///
+    /// Same as [init()] but returns [AdxlError::VerifyMismatch] on the first
+    /// register which does not hold the written value, so a flaky bus can not
+    /// silently leave the device half configured.
+    /// The constructors use the faster unverified [init()].
+    ///
+    /// [init()]: ../trait.Adxl345Writer.html#tymethod.init
+    /// [AdxlError::VerifyMismatch]: ../enum.AdxlError.html#variant.VerifyMismatch
+    pub fn init_verified(&mut self) -> Result {
+        self.init_registers_verified(false)
+    }
+    /// Probes for the device by reading the device ID before anything is
+    /// written.
+    ///
+    /// A failed read is reported as [AdxlError::DeviceNotFound] which
+    /// distinguishes nothing being at the address from a device which is
+    /// present but rejects a later write.
+    /// A device ID other than [DEVICE_ID] is reported as
+    /// [AdxlError::UnexpectedDeviceId] as some other chip is at the address.
+    ///
+    /// [AdxlError::DeviceNotFound]: ../enum.AdxlError.html#variant.DeviceNotFound
+    /// [DEVICE_ID]: ../constant.DEVICE_ID.html
+    /// [AdxlError::UnexpectedDeviceId]: ../enum.AdxlError.html#variant.UnexpectedDeviceId
+    fn probe(&self, address: u16) -> Result {
+        let found = self
+            .device_id()
+            .map_err(|_| AdxlError::DeviceNotFound { address })?;
+        if found != DEVICE_ID {
+            return Err(AdxlError::UnexpectedDeviceId { address, found });
+        }
+        Ok(())
+    }
+    /// Retargets the device to a new slave address.
+    ///
+    /// Useful when the `ALT ADDRESS` pin is toggled to switch between boards
+    /// sharing the same bus. The device is probed at the new address but
+    /// ___NOT___ re-initialized.
+    ///
+    /// ## Arguments
+    /// * `slave` - Address of ADXL345 device. Must be 0x53 or 0x1d else
+    ///   [AdxlError::InvalidBusParams] is returned.
+    ///
+    /// [AdxlError::InvalidBusParams]: ../enum.AdxlError.html#variant.InvalidBusParams
+    pub fn set_slave_address(&mut self, slave: u16) -> Result {
+        check_address(slave)?;
+        self.set_slave_address_unchecked(slave)
+    }
+    /// Retargets the device to a new slave address without checking it is one
+    /// of the two addresses the device supports.
+    ///
+    /// The device is still probed at the new address.
+    /// If the probe fails the previous address is restored before the error
+    /// is returned, so the device keeps talking to the old board.
+    /// A device made with [from_i2c()] has no known previous address and is
+    /// left at the new one.
+    ///
+    /// ## Arguments
+    /// * `slave` - Address of ADXL345 device.
+    ///
+    /// [from_i2c()]: struct.Device.html#method.from_i2c
+    pub fn set_slave_address_unchecked(&mut self, slave: u16) -> Result {
+        log_debug!("Retargeting I²C device to address {:#04x}", slave);
+        self.inner.transport_mut().set_slave_address(slave)?;
+        if let Err(e) = self.probe(slave) {
+            if let Some(previous) = self.address {
+                log_warn!(
+                    "Probe failed at address {:#04x}, restoring address {:#04x}",
+                    slave,
+                    previous
+                );
+                self.inner.transport_mut().set_slave_address(previous)?;
+            }
+            return Err(e);
+        }
+        self.address = Some(slave);
+        Ok(())
+    }
+    /// Sets the maximum duration of each bus transaction.
+    ///
+    /// Transactions which take longer fail with an [AdxlError::I2c] error
+    /// instead of blocking, which lets the driver fail fast on a bad bus.
+    /// Until set the bus adapter's own default is used, typically 1 second.
+    ///
+    /// ___Note:___ _The timeout has a 10 ms resolution and a too short timeout
+    /// may cause spurious failures of otherwise good transactions._
+    ///
+    /// ## Arguments
+    /// * `ms` - Timeout in milliseconds.
+    ///
+    /// [AdxlError::I2c]: ../enum.AdxlError.html#variant.I2c
+    pub fn set_timeout(&mut self, ms: u32) -> Result {
+        self.inner.transport_mut().set_timeout(ms)?;
+        Ok(())
+    }
+    /// Enables or disables read-back verification of each command.
+    ///
+    /// When enabled every write, including all the typed setters, is followed
+    /// by a read of the same register and an [AdxlError::VerifyMismatch] is
+    /// returned if the values differ.
+    ///
+    /// ___Note:___ _This doubles the bus traffic of each write._
+    ///
+    /// [AdxlError::VerifyMismatch]: ../enum.AdxlError.html#variant.VerifyMismatch
+    pub fn set_verify(&mut self, verify: bool) {
+        self.inner.set_verify(verify);
+    }
+}
+
+impl Transport for I2c {
+    fn read_register(&self, register: u8) -> AdxlResult<u8> {
+        let buf = &mut [0u8; 1];
+        self.block_read(register, buf)?;
+        Ok(buf[0])
+    }
+    fn write_register(&mut self, register: u8, byte: u8) -> Result {
+        self.block_write(register, &[byte])?;
+        Ok(())
+    }
+    fn read_burst(&self, start: u8, buf: &mut [u8]) -> Result {
+        check_range(start, buf.len())?;
+        self.write_read(&[start], buf)?;
+        Ok(())
+    }
+    fn write_burst(&mut self, start: u8, bytes: &[u8]) -> Result {
+        check_range(start, bytes.len())?;
+        self.block_write(start, bytes)?;
+        Ok(())
+    }
 }
 
 impl Adxl345 for Device {}
@@ -59,28 +308,45 @@ impl Adxl345Init for Device {}
 
 impl Adxl345Reader for Device {
     fn access(&self, register: u8) -> AdxlResult<u8> {
-        let buf = &mut [0u8; 1];
-        self.bus.block_read(register, buf)?;
-        Ok(buf[0])
+        self.inner.access(register)
     }
     fn acceleration(&self) -> AdxlResult<(i16, i16, i16)> {
-        let register = 0x32;
-        let buf = &mut [0u8; 6];
-        self.bus.block_read(register, buf)?;
-        Ok((
-            i16::from_le_bytes([buf[0], buf[1]]),
-            i16::from_le_bytes([buf[2], buf[3]]),
-            i16::from_le_bytes([buf[4], buf[5]]),
-        ))
+        self.inner.acceleration()
+    }
+    fn access_range(&self, start: u8, len: usize) -> AdxlResult<Vec<u8>> {
+        self.inner.access_range(start, len)
     }
 }
 
 impl Adxl345Writer for Device {
     fn command(&mut self, register: u8, byte: u8) -> Result {
-        self.bus.block_write(register, &[byte])?;
-        Ok(())
+        self.inner.command(register, byte)
     }
     fn init(&mut self) -> Result {
+        log_debug!("Initializing I²C device registers");
         self.init_registers(false)
     }
+    fn set_offset_adjustment<X, Y, Z>(&mut self, x: X, y: Y, z: Z) -> Result
+    where
+        X: Into<Option<i8>>,
+        Y: Into<Option<i8>>,
+        Z: Into<Option<i8>>,
+    {
+        self.inner.set_offset_adjustment(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_address_accepts_only_device_addresses() {
+        assert!(check_address(0x53).is_ok());
+        assert!(check_address(0x1d).is_ok());
+        assert!(matches!(
+            check_address(0x54),
+            Err(AdxlError::InvalidBusParams)
+        ));
+    }
 }
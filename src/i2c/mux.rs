@@ -0,0 +1,110 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains the I²C multiplexer transport wrapper.
+
+use rppal::i2c::I2c;
+
+use crate::{transport::Transport, AdxlError, AdxlResult, Result};
+
+/// Transport wrapper which selects a channel of a TCA9548A style I²C
+/// multiplexer before each access of the inner transport.
+///
+/// Lets several identical devices at the same address be used on one bus by
+/// giving each its own wrapper with a different channel.
+/// The channel-select byte is written before every read or write so the
+/// wrappers can be freely interleaved.
+///
+/// ```ignore
+/// let mut inner = I2c::new()?;
+/// inner.set_slave_address(0x53)?;
+/// let mux = I2cMux::new(inner, I2c::new()?, 0x70, 2)?;
+/// let mut adxl345 = transport::Device::new(mux)?;
+/// ```
+#[derive(Debug)]
+pub struct I2cMux<T: Transport> {
+    /// Transport of the device behind the multiplexer.
+    inner: T,
+    /// Bus handle addressed to the multiplexer.
+    mux: I2c,
+    /// Multiplexer channel (0-7) of the device.
+    channel: u8,
+}
+
+impl<T: Transport> I2cMux<T> {
+    /// Constructor with the inner transport and the multiplexer bus handle.
+    ///
+    /// ## Arguments
+    /// * `inner` - Transport of the device behind the multiplexer, for
+    ///   example an [I2c] with the slave address of the device already set.
+    /// * `mux` - Open I²C bus used to talk to the multiplexer.
+    ///   Its slave address is set to `address`.
+    /// * `address` - Address of the multiplexer.
+    /// * `channel` - Multiplexer channel of the device. Must be 0-7 else
+    ///   [AdxlError::InvalidBusParams] is returned.
+    ///
+    /// [I2c]: https://docs.golemparts.com/rppal/0.11.3/rppal/i2c/struct.I2c.html
+    /// [AdxlError::InvalidBusParams]: ../enum.AdxlError.html#variant.InvalidBusParams
+    pub fn new(inner: T, mut mux: I2c, address: u16, channel: u8) -> AdxlResult<Self> {
+        if channel > 7 {
+            return Err(AdxlError::InvalidBusParams);
+        }
+        mux.set_slave_address(address)?;
+        Ok(I2cMux {
+            inner,
+            mux,
+            channel,
+        })
+    }
+    /// Access the multiplexer channel of the device.
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+    /// Returns the inner transport consuming the wrapper.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+    /// Writes the channel-select byte to the multiplexer.
+    fn select(&self) -> Result {
+        log_trace!("select I²C multiplexer channel {}", self.channel);
+        self.mux.smbus_send_byte(1 << self.channel)?;
+        Ok(())
+    }
+}
+
+impl<T: Transport> Transport for I2cMux<T> {
+    fn read_register(&self, register: u8) -> AdxlResult<u8> {
+        self.select()?;
+        self.inner.read_register(register)
+    }
+    fn write_register(&mut self, register: u8, byte: u8) -> Result {
+        self.select()?;
+        self.inner.write_register(register, byte)
+    }
+    fn read_burst(&self, start: u8, buf: &mut [u8]) -> Result {
+        self.select()?;
+        self.inner.read_burst(start, buf)
+    }
+    fn write_burst(&mut self, start: u8, bytes: &[u8]) -> Result {
+        self.select()?;
+        self.inner.write_burst(start, bytes)
+    }
+}
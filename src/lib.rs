@@ -22,23 +22,48 @@
 //! Provides a complete ADXL345 compatible command set and supporting code.
 //!
 //! This is meant to be a hardware level driver interface for the device.
+//!
+//! The I²C and SPI drivers are behind the default-on `i2c` and `spi` features
+//! so either can be left out of a build.
 
 #[macro_use]
 extern crate bitflags;
 #[macro_use]
 extern crate c2rust_bitfields;
+#[macro_use]
+mod logging;
 
 mod cmd;
+mod config;
+mod csv;
+mod dump;
 mod error;
+#[cfg(feature = "i2c")]
 pub mod i2c;
+mod motion;
+mod orientation;
+mod pacing;
+mod recorder;
+#[cfg(feature = "spi")]
 pub mod spi;
+pub mod transport;
+mod units;
 
 pub(crate) use crate::cmd::Adxl345Init;
 pub use crate::{
     cmd::{
-        ATStatus, ActivityMode, Adxl345, Adxl345Reader, Adxl345Writer, BandwidthRateControl,
-        DataFormat, FifoControl, FifoStatus, IntControlMode, IntMapMode, IntSource, PowerControl,
-        Tap, TapMode,
+        parse_sample, ATStatus, ActiveLevel, ActivityMode, ActivityModeBuilder, Adxl345,
+        Adxl345Reader, Adxl345Writer, BandwidthRateControl, Coupling, DataFormat, FifoControl,
+        FifoMode, FifoStatus, IntControlMode, IntMapMode, IntPin, IntSource, IntSourceKind,
+        OutputDataRate, PowerControl, Range, Tap, TapConfig, TapEvent, TapMode, DEVICE_ID,
     },
+    config::Adxl345Config,
+    csv::CsvUnits,
+    dump::{Register, RegisterDump},
     error::{AdxlError, AdxlResult, Result},
+    motion::{MotionDetector, MotionState},
+    orientation::HeldOrientation,
+    pacing::{PacedReader, SamplesUntil},
+    recorder::{RingRecorder, TimedSample},
+    units::{Counts, Milligravity},
 };
@@ -0,0 +1,61 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains the optional `log` crate integration.
+//!
+//! The macros expand to nothing unless the `log` feature is enabled so there
+//! is no overhead when it is off.
+
+/// Emits a `trace!` log entry when the `log` feature is enabled.
+macro_rules! log_trace {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        log::trace!($($arg)+);
+    };
+}
+
+/// Emits a `debug!` log entry when the `log` feature is enabled.
+macro_rules! log_debug {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        log::debug!($($arg)+);
+    };
+}
+
+/// Emits a `warn!` log entry when the `log` feature is enabled.
+macro_rules! log_warn {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        log::warn!($($arg)+);
+    };
+}
+
+/// Returns the datasheet name of a register for use in log entries.
+///
+/// ## Arguments
+/// * `register` - Address (offset) of the register.
+#[cfg(feature = "log")]
+pub(crate) fn register_name(register: u8) -> &'static str {
+    use std::convert::TryFrom;
+    crate::Register::try_from(register)
+        .map(|register| register.name())
+        .unwrap_or("Reserved")
+}
@@ -0,0 +1,150 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains a motion detector with hysteresis which works on samples from any
+//! source.
+
+use crate::{cmd::sample_to_g, AdxlError, AdxlResult, DataFormat};
+
+/// Motion states reported by [MotionDetector].
+///
+/// [MotionDetector]: struct.MotionDetector.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MotionState {
+    /// Acceleration has stayed within the exit threshold.
+    Still,
+    /// Acceleration has gone past the enter threshold.
+    Moving,
+}
+
+/// Threshold with hysteresis motion detector.
+///
+/// Motion is measured as how far the magnitude of the acceleration is from
+/// the 1 g of gravity.
+/// The state changes to [MotionState::Moving] once the motion exceeds the
+/// enter threshold and only goes back to [MotionState::Still] once it drops
+/// below the lower exit threshold, which stops the state from chattering when
+/// the motion hovers around a single threshold.
+///
+/// The detector only works on the samples it is given so it can be used with
+/// either driver or with recorded samples.
+///
+/// [MotionState::Moving]: enum.MotionState.html#variant.Moving
+/// [MotionState::Still]: enum.MotionState.html#variant.Still
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MotionDetector {
+    enter_g: f64,
+    exit_g: f64,
+    state: MotionState,
+}
+
+impl MotionDetector {
+    /// Constructor which starts in the [MotionState::Still] state.
+    ///
+    /// Returns [AdxlError::ValueOutOfRange] if `exit_g` is above `enter_g` or
+    /// if either threshold is negative.
+    ///
+    /// ## Arguments
+    /// * `enter_g` - Motion in g above which the state changes to moving.
+    /// * `exit_g` - Motion in g below which the state changes back to still.
+    ///
+    /// [MotionState::Still]: enum.MotionState.html#variant.Still
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    pub fn new(enter_g: f64, exit_g: f64) -> AdxlResult<Self> {
+        if !(0.0..=enter_g).contains(&exit_g) {
+            return Err(AdxlError::ValueOutOfRange {
+                requested: exit_g,
+                max: enter_g,
+            });
+        }
+        Ok(MotionDetector {
+            enter_g,
+            exit_g,
+            state: MotionState::Still,
+        })
+    }
+    /// Access the current state without updating it.
+    pub fn state(&self) -> MotionState {
+        self.state
+    }
+    /// Updates the state with a new sample and returns the new state.
+    ///
+    /// ## Arguments
+    /// * `sample` - Acceleration of each axis in g, for example from
+    ///   [acceleration_g()].
+    ///
+    /// [acceleration_g()]: trait.Adxl345Reader.html#method.acceleration_g
+    pub fn update(&mut self, sample: (f64, f64, f64)) -> MotionState {
+        let (x, y, z) = sample;
+        let motion = ((x * x + y * y + z * z).sqrt() - 1.0).abs();
+        self.state = match self.state {
+            MotionState::Still if motion > self.enter_g => MotionState::Moving,
+            MotionState::Moving if motion < self.exit_g => MotionState::Still,
+            state => state,
+        };
+        self.state
+    }
+    /// Updates the state with a new raw sample and returns the new state.
+    ///
+    /// ## Arguments
+    /// * `sample` - Raw acceleration of each axis, for example from
+    ///   [acceleration()].
+    /// * `format` - Data format in effect when the sample was read.
+    ///
+    /// [acceleration()]: trait.Adxl345Reader.html#tymethod.acceleration
+    pub fn update_raw(&mut self, sample: (i16, i16, i16), format: DataFormat) -> MotionState {
+        self.update(sample_to_g(sample, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample along the Z-axis with the given motion in g added to gravity.
+    fn sample(motion: f64) -> (f64, f64, f64) {
+        (0.0, 0.0, 1.0 + motion)
+    }
+
+    #[test]
+    fn hysteresis() {
+        let mut detector = MotionDetector::new(0.5, 0.2).unwrap();
+        assert_eq!(detector.update(sample(0.4)), MotionState::Still);
+        assert_eq!(detector.update(sample(0.6)), MotionState::Moving);
+        // Between the two thresholds the state is kept.
+        assert_eq!(detector.update(sample(0.3)), MotionState::Moving);
+        assert_eq!(detector.update(sample(-0.3)), MotionState::Moving);
+        assert_eq!(detector.update(sample(0.1)), MotionState::Still);
+        assert_eq!(detector.update(sample(0.3)), MotionState::Still);
+    }
+
+    #[test]
+    fn new_rejects_exit_above_enter() {
+        assert!(matches!(
+            MotionDetector::new(0.2, 0.5),
+            Err(AdxlError::ValueOutOfRange { .. })
+        ));
+        assert!(MotionDetector::new(0.2, -0.1).is_err());
+        assert!(MotionDetector::new(0.5, 0.5).is_ok());
+    }
+}
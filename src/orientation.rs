@@ -0,0 +1,190 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains a held orientation detector which works on samples from any
+//! source.
+
+use crate::{
+    cmd::{axis_angle_deg, sample_to_g},
+    AdxlError, AdxlResult, DataFormat,
+};
+use std::{
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+/// Debounced detector of the device being held at a target tilt.
+///
+/// The tilt is measured as the pitch and roll of the device, the angles of
+/// the X and Y-axis from the horizontal plane, the same as the first two
+/// angles of [inclination_per_axis()].
+/// The orientation is held once every sample for at least the hold duration
+/// has had both angles within their target ranges.
+/// Any sample outside of the ranges restarts the hold.
+///
+/// The detector only works on the samples and times it is given so it can be
+/// used with either driver or with recorded samples.
+///
+/// ___Note:___ _The device is assumed to be roughly static so the measured
+/// acceleration is only gravity. Any motion adds error which can break the
+/// hold when the target ranges are narrow._
+///
+/// [inclination_per_axis()]: trait.Adxl345Reader.html#method.inclination_per_axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeldOrientation {
+    pitch: RangeInclusive<f64>,
+    roll: RangeInclusive<f64>,
+    hold: Duration,
+    since: Option<Instant>,
+    held: bool,
+}
+
+impl HeldOrientation {
+    /// Constructor which starts with the orientation not held.
+    ///
+    /// Returns [AdxlError::ValueOutOfRange] if either range is empty.
+    ///
+    /// ## Arguments
+    /// * `pitch` - Target X-axis angle range in degrees between -90° and 90°.
+    /// * `roll` - Target Y-axis angle range in degrees between -90° and 90°.
+    /// * `hold` - How long the tilt must stay within the target ranges.
+    ///
+    /// [AdxlError::ValueOutOfRange]: enum.AdxlError.html#variant.ValueOutOfRange
+    pub fn new(
+        pitch: RangeInclusive<f64>,
+        roll: RangeInclusive<f64>,
+        hold: Duration,
+    ) -> AdxlResult<Self> {
+        for range in [&pitch, &roll] {
+            if range.is_empty() {
+                return Err(AdxlError::ValueOutOfRange {
+                    requested: *range.start(),
+                    max: *range.end(),
+                });
+            }
+        }
+        Ok(HeldOrientation {
+            pitch,
+            roll,
+            hold,
+            since: None,
+            held: false,
+        })
+    }
+    /// Access if the orientation is currently held without updating it.
+    pub fn is_held(&self) -> bool {
+        self.held
+    }
+    /// Restarts the hold so the orientation is not held until the full hold
+    /// duration has passed again.
+    pub fn reset(&mut self) {
+        self.since = None;
+        self.held = false;
+    }
+    /// Updates the detector with a new sample and returns if the orientation
+    /// is held.
+    ///
+    /// ## Arguments
+    /// * `time` - Time the sample was read.
+    ///   Samples must be given in time order.
+    /// * `sample` - Acceleration of each axis in g, for example from
+    ///   [acceleration_g()].
+    ///
+    /// [acceleration_g()]: trait.Adxl345Reader.html#method.acceleration_g
+    pub fn update(&mut self, time: Instant, sample: (f64, f64, f64)) -> bool {
+        let (x, y, z) = sample;
+        if self.pitch.contains(&axis_angle_deg(x, y, z))
+            && self.roll.contains(&axis_angle_deg(y, x, z))
+        {
+            let since = *self.since.get_or_insert(time);
+            self.held = time.saturating_duration_since(since) >= self.hold;
+        } else {
+            self.reset();
+        }
+        self.held
+    }
+    /// Updates the detector with a new raw sample and returns if the
+    /// orientation is held.
+    ///
+    /// ## Arguments
+    /// * `time` - Time the sample was read.
+    ///   Samples must be given in time order.
+    /// * `sample` - Raw acceleration of each axis, for example from
+    ///   [acceleration()].
+    /// * `format` - Data format in effect when the sample was read.
+    ///
+    /// [acceleration()]: trait.Adxl345Reader.html#tymethod.acceleration
+    pub fn update_raw(
+        &mut self,
+        time: Instant,
+        sample: (i16, i16, i16),
+        format: DataFormat,
+    ) -> bool {
+        self.update(time, sample_to_g(sample, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample of gravity with the X-axis tilted `pitch` degrees up.
+    fn tilted(pitch: f64) -> (f64, f64, f64) {
+        let pitch = pitch.to_radians();
+        (pitch.sin(), 0.0, pitch.cos())
+    }
+
+    fn detector() -> HeldOrientation {
+        HeldOrientation::new(20.0..=40.0, -10.0..=10.0, Duration::from_millis(500)).unwrap()
+    }
+
+    #[test]
+    fn held_after_hold_elapses() {
+        let mut held = detector();
+        let start = Instant::now();
+        assert!(!held.update(start, tilted(30.0)));
+        assert!(!held.update(start + Duration::from_millis(499), tilted(25.0)));
+        assert!(held.update(start + Duration::from_millis(500), tilted(35.0)));
+        assert!(held.is_held());
+    }
+
+    #[test]
+    fn out_of_range_sample_restarts_hold() {
+        let mut held = detector();
+        let start = Instant::now();
+        held.update(start, tilted(30.0));
+        assert!(held.update(start + Duration::from_millis(600), tilted(30.0)));
+        assert!(!held.update(start + Duration::from_millis(700), tilted(50.0)));
+        assert!(!held.update(start + Duration::from_millis(800), tilted(30.0)));
+        assert!(!held.update(start + Duration::from_millis(1200), tilted(30.0)));
+        assert!(held.update(start + Duration::from_millis(1300), tilted(30.0)));
+    }
+
+    #[test]
+    fn new_rejects_empty_range() {
+        let hold = Duration::from_millis(500);
+        assert!(matches!(
+            HeldOrientation::new(40.0..=20.0, -10.0..=10.0, hold),
+            Err(AdxlError::ValueOutOfRange { .. })
+        ));
+        assert!(HeldOrientation::new(20.0..=40.0, 10.0..=-10.0, hold).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains a sample rate paced acceleration reader.
+
+use crate::{Adxl345Reader, AdxlError, AdxlResult};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+/// Reads acceleration data at a stable target rate.
+///
+/// Instead of sleeping a fixed amount between reads each read is scheduled
+/// against a fixed timeline so the time spent doing the read itself does not
+/// cause the effective sample rate to drift.
+///
+/// When a read falls more than a full period behind the schedule the timeline
+/// is restarted from the late read instead of issuing a burst of catch-up
+/// reads.
+///
+/// Can also be used as an (endless) iterator of samples.
+#[derive(Debug)]
+pub struct PacedReader<'a, R: Adxl345Reader + ?Sized> {
+    /// Reader used to access acceleration data.
+    reader: &'a R,
+    /// Target time between samples.
+    period: Duration,
+    /// Scheduled time of the next sample.
+    next: Option<Instant>,
+    /// How late the last sample was started compared to its schedule.
+    last_jitter: Duration,
+    /// Largest jitter seen since construction or the last reset.
+    max_jitter: Duration,
+}
+
+impl<'a, R: Adxl345Reader + ?Sized> PacedReader<'a, R> {
+    /// Constructor with target sample rate.
+    ///
+    /// Returns [AdxlError::InvalidRate] if `rate_hz` is not a positive finite
+    /// number, is so small the period does not fit in a [Duration], or is so
+    /// large the period rounds to zero.
+    ///
+    /// ## Arguments
+    /// * `reader` - Device used to read acceleration data.
+    /// * `rate_hz` - Target sample rate in Hz.
+    ///
+    /// [AdxlError::InvalidRate]: enum.AdxlError.html#variant.InvalidRate
+    /// [Duration]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn new(reader: &'a R, rate_hz: f64) -> AdxlResult<Self> {
+        let period = Duration::try_from_secs_f64(1.0 / rate_hz)
+            .ok()
+            .filter(|period| rate_hz.is_finite() && !period.is_zero())
+            .ok_or(AdxlError::InvalidRate(rate_hz))?;
+        Ok(Self::with_period(reader, period))
+    }
+    /// Constructor with target time between samples.
+    ///
+    /// ## Arguments
+    /// * `reader` - Device used to read acceleration data.
+    /// * `period` - Target time between samples.
+    pub fn with_period(reader: &'a R, period: Duration) -> Self {
+        PacedReader {
+            reader,
+            period,
+            next: None,
+            last_jitter: Duration::default(),
+            max_jitter: Duration::default(),
+        }
+    }
+    /// Access how late the last sample was started compared to its schedule.
+    pub fn jitter(&self) -> Duration {
+        self.last_jitter
+    }
+    /// Access the largest jitter seen since construction or the last reset.
+    pub fn max_jitter(&self) -> Duration {
+        self.max_jitter
+    }
+    /// Access the target time between samples.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+    /// Waits until the next scheduled time and then reads a sample.
+    ///
+    /// The first call reads immediately and starts the schedule.
+    pub fn read(&mut self) -> AdxlResult<(i16, i16, i16)> {
+        let deadline = *self.next.get_or_insert_with(Instant::now);
+        let now = Instant::now();
+        if deadline > now {
+            sleep(deadline - now);
+        }
+        let start = Instant::now();
+        self.last_jitter = start.saturating_duration_since(deadline);
+        self.max_jitter = self.max_jitter.max(self.last_jitter);
+        let next = deadline + self.period;
+        self.next = Some(if next < start {
+            start + self.period
+        } else {
+            next
+        });
+        self.reader.acceleration()
+    }
+    /// Restarts the schedule and clears the jitter statistics.
+    pub fn reset(&mut self) {
+        self.next = None;
+        self.last_jitter = Duration::default();
+        self.max_jitter = Duration::default();
+    }
+}
+
+impl<'a, R: Adxl345Reader + ?Sized> Iterator for PacedReader<'a, R> {
+    type Item = AdxlResult<(i16, i16, i16)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.read())
+    }
+}
+
+/// Paced stream of acceleration samples which stops once a shared flag is
+/// cleared.
+///
+/// Typically the flag is cleared from a Ctrl-C handler to end a sampling loop
+/// cleanly.
+///
+/// Created by [samples_until()].
+///
+/// [samples_until()]: trait.Adxl345Reader.html#method.samples_until
+#[derive(Debug)]
+pub struct SamplesUntil<'a, R: Adxl345Reader + ?Sized> {
+    /// Reader used to pace the samples.
+    paced: PacedReader<'a, R>,
+    /// true: keep sampling; false: stop.
+    running: Arc<AtomicBool>,
+}
+
+impl<'a, R: Adxl345Reader + ?Sized> SamplesUntil<'a, R> {
+    /// Constructor.
+    ///
+    /// ## Arguments
+    /// * `reader` - Device used to read acceleration data.
+    /// * `running` - Flag which keeps the stream going while true.
+    /// * `period` - Target time between samples.
+    pub fn new(reader: &'a R, running: Arc<AtomicBool>, period: Duration) -> Self {
+        SamplesUntil {
+            paced: PacedReader::with_period(reader, period),
+            running,
+        }
+    }
+    /// Access the paced reader used for the samples, for example to check the
+    /// jitter.
+    pub fn paced(&self) -> &PacedReader<'a, R> {
+        &self.paced
+    }
+}
+
+impl<'a, R: Adxl345Reader + ?Sized> Iterator for SamplesUntil<'a, R> {
+    type Item = AdxlResult<(i16, i16, i16)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.running.load(Ordering::SeqCst) {
+            return None;
+        }
+        Some(self.paced.read())
+    }
+}
@@ -0,0 +1,98 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains a ring buffer recorder of recent acceleration samples.
+
+use crate::{Adxl345Reader, AdxlResult};
+use std::time::Instant;
+
+/// Acceleration sample together with the time it was read.
+pub type TimedSample = (Instant, (i16, i16, i16));
+
+/// Keeps the last `N` acceleration samples, each with the time it was read, in
+/// a fixed-size ring buffer.
+///
+/// Useful for post-mortem analysis of rare events where a [snapshot()] of the
+/// samples leading up to the event is taken once its interrupt fires.
+/// Unlike the 32 entry hardware fifo the capture window is only limited by
+/// `N`.
+///
+/// [snapshot()]: struct.RingRecorder.html#method.snapshot
+#[derive(Debug)]
+pub struct RingRecorder<'a, R: Adxl345Reader + ?Sized, const N: usize> {
+    /// Reader used to access acceleration data.
+    reader: &'a R,
+    /// Recorded samples where `None` marks a slot not yet filled.
+    ring: [Option<TimedSample>; N],
+    /// Index of the slot the next sample is written to.
+    next: usize,
+}
+
+impl<'a, R: Adxl345Reader + ?Sized, const N: usize> RingRecorder<'a, R, N> {
+    /// Constructor with an empty ring.
+    ///
+    /// ## Arguments
+    /// * `reader` - Device used to read acceleration data.
+    pub fn new(reader: &'a R) -> Self {
+        RingRecorder {
+            reader,
+            ring: [None; N],
+            next: 0,
+        }
+    }
+    /// Empties the ring.
+    pub fn clear(&mut self) {
+        self.ring = [None; N];
+        self.next = 0;
+    }
+    /// Check if no samples have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Access the number of samples currently held, at most `N`.
+    pub fn len(&self) -> usize {
+        self.ring.iter().filter(|slot| slot.is_some()).count()
+    }
+    /// Appends a sample which was read elsewhere, overwriting the oldest one
+    /// once the ring is full.
+    ///
+    /// ## Arguments
+    /// * `time` - Time the sample was read.
+    /// * `sample` - Acceleration sample to be recorded.
+    pub fn push(&mut self, time: Instant, sample: (i16, i16, i16)) {
+        if N == 0 {
+            return;
+        }
+        self.ring[self.next] = Some((time, sample));
+        self.next = (self.next + 1) % N;
+    }
+    /// Reads a sample, appends it to the ring, and returns it.
+    pub fn record(&mut self) -> AdxlResult<(i16, i16, i16)> {
+        let sample = self.reader.acceleration()?;
+        self.push(Instant::now(), sample);
+        Ok(sample)
+    }
+    /// Copies the recorded samples, oldest first.
+    pub fn snapshot(&self) -> Vec<TimedSample> {
+        let (newest, oldest) = self.ring.split_at(self.next);
+        oldest.iter().chain(newest).flatten().copied().collect()
+    }
+}
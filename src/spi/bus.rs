@@ -0,0 +1,162 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains the SPI register protocol transport over any full-duplex bus.
+
+use rppal::spi::Spi;
+use std::cell::RefCell;
+
+use crate::{cmd::check_range, transport::Transport, AdxlError, AdxlResult, Result};
+
+/// Size of the transfer buffers used for burst transfers which is one command
+/// byte plus the full 64 register address space.
+const BURST_BUFFER: usize = 0x41;
+
+/// Minimal set of full-duplex SPI bus operations needed by [SpiTransport].
+///
+/// Implemented for the [RPPAL SPI] bus and can be implemented for other SPI
+/// buses, for example a USB-to-SPI bridge, to reuse the device register
+/// framing.
+///
+/// [SpiTransport]: struct.SpiTransport.html
+/// [RPPAL SPI]: https://docs.golemparts.com/rppal/0.11.3/rppal/spi/index.html
+pub trait SpiBus {
+    /// Full-duplex transfer which writes `write_buf` while filling
+    /// `read_buf`.
+    ///
+    /// ## Arguments
+    /// * `read_buf` - Buffer filled with the bytes read.
+    /// * `write_buf` - Bytes written, the same length as `read_buf`.
+    fn transfer(&self, read_buf: &mut [u8], write_buf: &[u8]) -> Result;
+    /// Write only transfer.
+    ///
+    /// ## Arguments
+    /// * `write_buf` - Bytes written.
+    fn write(&mut self, write_buf: &[u8]) -> Result;
+}
+
+impl SpiBus for Spi {
+    /// Returns [AdxlError::SpiBufferMismatch] instead of passing the buffers
+    /// to the bus when their lengths differ.
+    ///
+    /// [AdxlError::SpiBufferMismatch]: ../enum.AdxlError.html#variant.SpiBufferMismatch
+    fn transfer(&self, read_buf: &mut [u8], write_buf: &[u8]) -> Result {
+        if read_buf.len() != write_buf.len() {
+            return Err(AdxlError::SpiBufferMismatch {
+                read: read_buf.len(),
+                write: write_buf.len(),
+            });
+        }
+        Spi::transfer(self, read_buf, write_buf)?;
+        Ok(())
+    }
+    fn write(&mut self, write_buf: &[u8]) -> Result {
+        Spi::write(self, write_buf)?;
+        Ok(())
+    }
+}
+
+/// Transport which frames the device SPI register protocol over a
+/// full-duplex [SpiBus].
+///
+/// The burst transfer buffers are allocated once with the transport and
+/// reused by every transfer, so the hot acceleration read path does no per
+/// call buffer setup beyond the few bytes it actually transfers.
+///
+/// [SpiBus]: trait.SpiBus.html
+#[derive(Debug)]
+pub struct SpiTransport<B: SpiBus> {
+    /// Bus the transfers are done over.
+    bus: B,
+    /// Read and write buffers of burst read transfers.
+    ///
+    /// Only the command byte of the write buffer is ever changed so the rest
+    /// stays zero.
+    transfer_bufs: RefCell<([u8; BURST_BUFFER], [u8; BURST_BUFFER])>,
+    /// Write buffer of burst writes.
+    write_buf: [u8; BURST_BUFFER],
+}
+
+impl<B: SpiBus> SpiTransport<B> {
+    /// Constructor with the bus used for the transfers.
+    ///
+    /// ## Arguments
+    /// * `bus` - Full-duplex SPI bus, for example an [RPPAL SPI] bus opened
+    ///   in SPI mode 3.
+    ///
+    /// [RPPAL SPI]: https://docs.golemparts.com/rppal/0.11.3/rppal/spi/index.html
+    pub fn new(bus: B) -> Self {
+        SpiTransport {
+            bus,
+            transfer_bufs: RefCell::new(([0u8; BURST_BUFFER], [0u8; BURST_BUFFER])),
+            write_buf: [0u8; BURST_BUFFER],
+        }
+    }
+    /// Access the bus.
+    pub fn bus(&self) -> &B {
+        &self.bus
+    }
+    /// Mutable access to the bus.
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+    /// Consumes the transport returning the bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+}
+
+impl<B: SpiBus> Transport for SpiTransport<B> {
+    fn read_register(&self, register: u8) -> AdxlResult<u8> {
+        let mut read_buf = [0u8, 0u8];
+        debug_assert!(register <= 0x7F);
+        let write_buf = [(register & 0x7Fu8) | 0x80u8, 0u8];
+        self.bus.transfer(&mut read_buf, &write_buf)?;
+        Ok(read_buf[1])
+    }
+    fn write_register(&mut self, register: u8, byte: u8) -> Result {
+        debug_assert!(register <= 0x7F);
+        let write_buf = [(register & 0x7Fu8), byte];
+        self.bus.write(&write_buf)
+    }
+    fn read_burst(&self, start: u8, buf: &mut [u8]) -> Result {
+        check_range(start, buf.len())?;
+        let len = buf.len() + 1;
+        let (read_buf, write_buf) = &mut *self.transfer_bufs.borrow_mut();
+        write_buf[0] = (start & 0x7Fu8) | 0x80u8;
+        if buf.len() > 1 {
+            write_buf[0] |= 0x40u8;
+        }
+        self.bus.transfer(&mut read_buf[..len], &write_buf[..len])?;
+        buf.copy_from_slice(&read_buf[1..len]);
+        Ok(())
+    }
+    fn write_burst(&mut self, start: u8, bytes: &[u8]) -> Result {
+        check_range(start, bytes.len())?;
+        let len = bytes.len() + 1;
+        self.write_buf[0] = start & 0x7Fu8;
+        if bytes.len() > 1 {
+            self.write_buf[0] |= 0x40u8;
+        }
+        self.write_buf[1..len].copy_from_slice(bytes);
+        self.bus.write(&self.write_buf[..len])
+    }
+}
@@ -22,19 +22,47 @@
 // SOFTWARE.
 //! Contains the SPI driver for the device.
 
+mod bus;
+
 use rppal::spi::{Bus, Mode, SlaveSelect, Spi};
 
-use crate::{Adxl345, Adxl345Init, Adxl345Reader, Adxl345Writer, AdxlError, AdxlResult, Result};
+pub use self::bus::{SpiBus, SpiTransport};
+
+use crate::{
+    transport, Adxl345, Adxl345Init, Adxl345Reader, Adxl345Writer, AdxlError, AdxlResult, Result,
+    DEVICE_ID,
+};
+
+/// Maximum SPI clock speed in Hz supported by the device.
+pub const MAX_CLOCK_SPEED: u32 = 5_000_000;
+
+/// Checks the clock speed against the maximum of the bus which can be lower
+/// than the device maximum on some buses.
+fn check_clock_speed(bus: Option<u8>, clock_speed: u32, max: u32) -> Result {
+    if clock_speed > max {
+        return Err(AdxlError::InvalidClockSpeed {
+            bus,
+            clock_speed,
+            max,
+        });
+    }
+    Ok(())
+}
 
 /// SPI driver structure for the device.
 #[derive(Debug)]
 pub struct Device {
-    /// Holds the bus interface from the [RPPAL SPI] peripheral.
+    /// Holds the generic driver using the bus interface from the
+    /// [RPPAL SPI] peripheral as its transport.
     ///
     /// [RPPAL SPI]: https://docs.golemparts.com/rppal/0.13.1/rppal/spi/index.html
-    bus: Spi,
+    inner: transport::Device<SpiTransport<Spi>>,
     /// true: SPI 3-wire mode; false: SPI 4-wire mode.
     three_wire: bool,
+    /// SPI bus index, `None` when the bus was opened by the caller.
+    bus: Option<u8>,
+    /// Maximum clock speed in Hz of the bus and device.
+    max_clock_speed: u32,
 }
 
 impl Device {
@@ -44,12 +72,34 @@ impl Device {
     pub fn new() -> AdxlResult<Self> {
         Self::with_bus(0, 0, 1_000_000, false)
     }
+    /// Constructor from an already open [RPPAL SPI] bus.
+    ///
+    /// Useful when the bus is managed elsewhere and shared with other devices.
+    /// The bus is used as is so it should be configured for SPI mode 3 with a
+    /// clock speed of no more than 5 MHz. Only [init()] is run on it.
+    ///
+    /// ## Arguments
+    /// * `bus` - Open SPI bus.
+    /// * `three_wire` - true: SPI 3-wire mode; false: SPI 4-wire mode.
+    ///
+    /// [RPPAL SPI]: https://docs.golemparts.com/rppal/0.13.1/rppal/spi/index.html
+    /// [init()]: ../trait.Adxl345Writer.html#tymethod.init
+    pub fn from_spi(bus: Spi, three_wire: bool) -> AdxlResult<Self> {
+        log_debug!(
+            "Creating SPI device from an open bus, 3-wire: {}",
+            three_wire
+        );
+        let mut device = Device::uninit(bus, three_wire, None, MAX_CLOCK_SPEED);
+        device.init()?;
+        Ok(device)
+    }
     /// Constructor with bus index and slave-select index.
     ///
     /// ## Arguments
     /// * `bus` - SPI bus index (0-2).
     /// * `slave_select` - SPI slave-select index (0-2).
     /// * `clock_speed` - SPI clock speed in Hz.
+    ///   Must be no more than the device maximum of 5 MHz.
     /// * `three_wire` - true: SPI 3-wire mode; false: SPI 4-wire mode.
     pub fn with_bus(
         bus: u8,
@@ -57,46 +107,197 @@ impl Device {
         clock_speed: u32,
         three_wire: bool,
     ) -> AdxlResult<Self> {
-        let bus = match bus {
-            0 => Bus::Spi0,
-            1 => Bus::Spi1,
-            2 => Bus::Spi2,
-            /*
-            3 => Bus::Spi3,
-            4 => Bus::Spi4,
-            5 => Bus::Spi5,
-            6 => Bus::Spi6,
-            */
-            _ => return Err(AdxlError::InvalidBusParams),
-        };
-        let slave_select = match slave_select {
-            0 => SlaveSelect::Ss0,
-            1 => SlaveSelect::Ss1,
-            2 => SlaveSelect::Ss2,
-            /*
-            3 => SlaveSelect::Ss3,
-            4 => SlaveSelect::Ss4,
-            5 => SlaveSelect::Ss5,
-            6 => SlaveSelect::Ss6,
-            7 => SlaveSelect::Ss7,
-            8 => SlaveSelect::Ss8,
-            9 => SlaveSelect::Ss9,
-            10 => SlaveSelect::Ss10,
-            11 => SlaveSelect::Ss11,
-            12 => SlaveSelect::Ss12,
-            13 => SlaveSelect::Ss13,
-            14 => SlaveSelect::Ss14,
-            15 => SlaveSelect::Ss15,
-            */
-            _ => return Err(AdxlError::InvalidBusParams),
-        };
-        let mut device = Device {
-            bus: Spi::new(bus, slave_select, clock_speed, Mode::Mode3)?,
-            three_wire,
-        };
+        log_debug!(
+            "Creating SPI device on bus {} slave-select {} at {} Hz, 3-wire: {}",
+            bus,
+            slave_select,
+            clock_speed,
+            three_wire
+        );
+        let (spi, max_clock_speed) = open_bus(bus, slave_select, clock_speed)?;
+        let mut device = Device::uninit(spi, three_wire, Some(bus), max_clock_speed);
         device.init()?;
         Ok(device)
     }
+    /// Constructor with default bus parameters which leaves the device
+    /// configuration untouched.
+    ///
+    /// bus = 0; slave_select = 0; clock_speed = 1 MHz; 4-wire-SPI.
+    ///
+    /// See [with_bus_preserving()] for more information.
+    ///
+    /// [with_bus_preserving()]: struct.Device.html#method.with_bus_preserving
+    pub fn new_preserving() -> AdxlResult<Self> {
+        Self::with_bus_preserving(0, 0, 1_000_000, false)
+    }
+    /// Constructor with bus index and slave-select index which leaves the
+    /// device configuration untouched.
+    ///
+    /// Useful when attaching to a device already configured, for example by a
+    /// bootloader, where [with_bus()] would reset all the registers.
+    ///
+    /// In 4-wire mode nothing is written.
+    /// In 3-wire mode the `spi` bit of the data format register (DATA_FORMAT)
+    /// must be set before the device can be read at all, and since the
+    /// register can not be read before then the whole register is written
+    /// with only the `spi` bit set.
+    /// The range, resolution, and other data format bits are reset and must be
+    /// restored by the caller if they were changed from the defaults.
+    /// No other register is written.
+    ///
+    /// The device ID is read once to make sure the device is present.
+    ///
+    /// ## Arguments
+    /// * `bus` - SPI bus index (0-2).
+    /// * `slave_select` - SPI slave-select index (0-2).
+    /// * `clock_speed` - SPI clock speed in Hz.
+    ///   Must be no more than the device maximum of 5 MHz.
+    /// * `three_wire` - true: SPI 3-wire mode; false: SPI 4-wire mode.
+    ///
+    /// [with_bus()]: struct.Device.html#method.with_bus
+    pub fn with_bus_preserving(
+        bus: u8,
+        slave_select: u8,
+        clock_speed: u32,
+        three_wire: bool,
+    ) -> AdxlResult<Self> {
+        let (spi, max_clock_speed) = open_bus(bus, slave_select, clock_speed)?;
+        let mut device = Device::uninit(spi, three_wire, Some(bus), max_clock_speed);
+        if three_wire {
+            let register = 0x31;
+            device.command(register, 1 << 6)?;
+        }
+        device.probe(bus, slave_select)?;
+        Ok(device)
+    }
+    /// Wraps the open bus without touching the device.
+    fn uninit(spi: Spi, three_wire: bool, bus: Option<u8>, max_clock_speed: u32) -> Self {
+        Device {
+            inner: transport::Device::new_uninit(SpiTransport::new(spi)),
+            three_wire,
+            bus,
+            max_clock_speed,
+        }
+    }
+    /// Re-initializes the device registers and then reads each one back.
+    ///
+    /// Same as [init()] but returns [AdxlError::VerifyMismatch] on the first
+    /// register which does not hold the written value, so a flaky bus can not
+    /// silently leave the device half configured.
+    /// The constructors use the faster unverified [init()].
+    ///
+    /// [init()]: ../trait.Adxl345Writer.html#tymethod.init
+    /// [AdxlError::VerifyMismatch]: ../enum.AdxlError.html#variant.VerifyMismatch
+    pub fn init_verified(&mut self) -> Result {
+        self.init_registers_verified(self.three_wire)
+    }
+    /// Probes for the device by reading the device ID.
+    ///
+    /// A device ID other than [DEVICE_ID] is reported as
+    /// [AdxlError::SpiDeviceNotFound], which catches a missing chip whose data
+    /// line just reads back all zero or one bits.
+    ///
+    /// [DEVICE_ID]: ../constant.DEVICE_ID.html
+    /// [AdxlError::SpiDeviceNotFound]: ../enum.AdxlError.html#variant.SpiDeviceNotFound
+    fn probe(&self, bus: u8, slave_select: u8) -> Result {
+        let found = self.device_id()?;
+        if found != DEVICE_ID {
+            return Err(AdxlError::SpiDeviceNotFound {
+                bus,
+                slave_select,
+                found,
+            });
+        }
+        Ok(())
+    }
+    /// Check if the device was set up for SPI 3-wire mode.
+    ///
+    /// Returns the mode requested at construction, use [spi_mode()] to check
+    /// what the device register actually holds.
+    ///
+    /// [spi_mode()]: struct.Device.html#method.spi_mode
+    pub fn is_three_wire(&self) -> bool {
+        self.three_wire
+    }
+    /// Reads the `spi` bit of the data format register from the device.
+    ///
+    /// Returns `true` when the device is in 3-wire mode and `false` when in
+    /// 4-wire mode.
+    /// Useful to confirm a 3-wire initialization took effect when debugging
+    /// wiring.
+    pub fn spi_mode(&self) -> AdxlResult<bool> {
+        Ok(self.data_format_lossy()?.spi())
+    }
+    /// Enables or disables read-back verification of each command.
+    ///
+    /// When enabled every write, including all the typed setters, is followed
+    /// by a read of the same register and an [AdxlError::VerifyMismatch] is
+    /// returned if the values differ.
+    ///
+    /// ___Note:___ _This doubles the bus traffic of each write._
+    ///
+    /// [AdxlError::VerifyMismatch]: ../enum.AdxlError.html#variant.VerifyMismatch
+    pub fn set_verify(&mut self, verify: bool) {
+        self.inner.set_verify(verify);
+    }
+    /// Changes the SPI clock speed.
+    ///
+    /// ___Note:___ _Changing the clock speed while another thread is in the
+    /// middle of a transfer with the same bus is unsafe and can corrupt the
+    /// transfer._
+    ///
+    /// ## Arguments
+    /// * `hz` - SPI clock speed in Hz.
+    ///   Must be no more than the maximum of the bus the device was opened on,
+    ///   which is never more than the device maximum of 5 MHz.
+    pub fn set_clock_speed(&mut self, hz: u32) -> Result {
+        check_clock_speed(self.bus, hz, self.max_clock_speed)?;
+        self.inner.transport_mut().bus_mut().set_clock_speed(hz)?;
+        Ok(())
+    }
+}
+
+/// Opens the bus in the SPI mode used by the device after checking the bus
+/// parameters and returns it with the maximum clock speed of the bus.
+///
+/// The clock speed is checked against the maximum of the selected bus so a
+/// bus with a lower maximum than the device is reported as
+/// [AdxlError::InvalidClockSpeed] naming the bus and that lower maximum.
+///
+/// [AdxlError::InvalidClockSpeed]: ../enum.AdxlError.html#variant.InvalidClockSpeed
+fn open_bus(bus: u8, slave_select: u8, clock_speed: u32) -> AdxlResult<(Spi, u32)> {
+    // Only SPI0-2 are supported by RPPAL 0.11. The auxiliary buses 3-6 each
+    // have their own maximum which goes in their arm when they are supported.
+    let (spi_bus, max) = match bus {
+        0 => (Bus::Spi0, MAX_CLOCK_SPEED),
+        1 => (Bus::Spi1, MAX_CLOCK_SPEED),
+        2 => (Bus::Spi2, MAX_CLOCK_SPEED),
+        _ => return Err(AdxlError::InvalidBusParams),
+    };
+    check_clock_speed(Some(bus), clock_speed, max)?;
+    let slave_select = match slave_select {
+        0 => SlaveSelect::Ss0,
+        1 => SlaveSelect::Ss1,
+        2 => SlaveSelect::Ss2,
+        /*
+        3 => SlaveSelect::Ss3,
+        4 => SlaveSelect::Ss4,
+        5 => SlaveSelect::Ss5,
+        6 => SlaveSelect::Ss6,
+        7 => SlaveSelect::Ss7,
+        8 => SlaveSelect::Ss8,
+        9 => SlaveSelect::Ss9,
+        10 => SlaveSelect::Ss10,
+        11 => SlaveSelect::Ss11,
+        12 => SlaveSelect::Ss12,
+        13 => SlaveSelect::Ss13,
+        14 => SlaveSelect::Ss14,
+        15 => SlaveSelect::Ss15,
+        */
+        _ => return Err(AdxlError::InvalidBusParams),
+    };
+    let spi = Spi::new(spi_bus, slave_select, clock_speed, Mode::Mode3)?;
+    Ok((spi, max))
 }
 
 impl Adxl345 for Device {}
@@ -104,42 +305,30 @@ impl Adxl345Init for Device {}
 
 impl Adxl345Reader for Device {
     fn access(&self, register: u8) -> AdxlResult<u8> {
-        let mut read_buf = [0u8, 0u8];
-        debug_assert!(register <= 0x7F);
-        let write_buf = [(register & 0x7Fu8) | 0x80u8, 0u8];
-        self.bus.transfer(&mut read_buf, &write_buf)?;
-        Ok(read_buf[1])
+        self.inner.access(register)
     }
     fn acceleration(&self) -> AdxlResult<(i16, i16, i16)> {
-        let register = 0x32;
-        let mut read_buf = [0u8; 7];
-        debug_assert!(register <= 0x7F);
-        let write_buf = [
-            (register & 0x7Fu8) | 0x80u8 | 0x40u8,
-            0u8,
-            0u8,
-            0u8,
-            0u8,
-            0u8,
-            0u8,
-        ];
-        self.bus.transfer(&mut read_buf, &write_buf)?;
-        Ok((
-            i16::from_le_bytes([read_buf[1], read_buf[2]]),
-            i16::from_le_bytes([read_buf[3], read_buf[4]]),
-            i16::from_le_bytes([read_buf[5], read_buf[6]]),
-        ))
+        self.inner.acceleration()
+    }
+    fn access_range(&self, start: u8, len: usize) -> AdxlResult<Vec<u8>> {
+        self.inner.access_range(start, len)
     }
 }
 
 impl Adxl345Writer for Device {
     fn command(&mut self, register: u8, byte: u8) -> Result {
-        debug_assert!(register <= 0x7F);
-        let write_buf = [(register & 0x7Fu8), byte];
-        self.bus.write(&write_buf)?;
-        Ok(())
+        self.inner.command(register, byte)
     }
     fn init(&mut self) -> Result {
+        log_debug!("Initializing SPI device registers");
         self.init_registers(self.three_wire)
     }
+    fn set_offset_adjustment<X, Y, Z>(&mut self, x: X, y: Y, z: Z) -> Result
+    where
+        X: Into<Option<i8>>,
+        Y: Into<Option<i8>>,
+        Z: Into<Option<i8>>,
+    {
+        self.inner.set_offset_adjustment(x, y, z)
+    }
 }
@@ -0,0 +1,383 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains the bus transport abstraction and a driver generic over it.
+//!
+//! The register logic of the command set only needs a few bus operations so
+//! any bus can be used with the device by implementing [Transport] for it.
+//! The I²C driver is built on the [RPPAL] I²C bus implementing this trait
+//! and the SPI driver on a [SpiTransport] wrapping the [RPPAL] SPI bus.
+//!
+//! [Transport]: trait.Transport.html
+//! [SpiTransport]: ../spi/struct.SpiTransport.html
+//! [RPPAL]: https://docs.golemparts.com/rppal/0.11.3/rppal/index.html
+
+use crate::{
+    cmd::{check_range, verify_register, write_offsets},
+    parse_sample, Adxl345, Adxl345Init, Adxl345Reader, Adxl345Writer, AdxlResult, Result,
+};
+
+/// Minimal set of bus operations needed to talk to the device.
+pub trait Transport {
+    /// Reads a single register.
+    ///
+    /// ## Arguments
+    /// * `register` - Register address to be read.
+    fn read_register(&self, register: u8) -> AdxlResult<u8>;
+    /// Writes a single register.
+    ///
+    /// ## Arguments
+    /// * `register` - Register address to be written.
+    /// * `byte` - Byte of data to be written into the given register.
+    fn write_register(&mut self, register: u8, byte: u8) -> Result;
+    /// Reads a contiguous range of registers in a single bus transaction.
+    ///
+    /// Implementations must return [AdxlError::InvalidBusParams] instead of
+    /// reading past the last register (0x3f).
+    ///
+    /// ## Arguments
+    /// * `start` - First register address to be read.
+    /// * `buf` - Buffer filled with the register values starting at `start`.
+    ///
+    /// [AdxlError::InvalidBusParams]: ../enum.AdxlError.html#variant.InvalidBusParams
+    fn read_burst(&self, start: u8, buf: &mut [u8]) -> Result;
+    /// Writes a contiguous range of registers.
+    ///
+    /// The default implementation writes each register individually so
+    /// transports should override it with a single multi-byte bus transaction
+    /// when possible.
+    /// Implementations must return [AdxlError::InvalidBusParams] instead of
+    /// writing past the last register (0x3f).
+    ///
+    /// ## Arguments
+    /// * `start` - First register address to be written.
+    /// * `bytes` - Bytes of data to be written starting at `start`.
+    ///
+    /// [AdxlError::InvalidBusParams]: ../enum.AdxlError.html#variant.InvalidBusParams
+    fn write_burst(&mut self, start: u8, bytes: &[u8]) -> Result {
+        check_range(start, bytes.len())?;
+        for (i, byte) in bytes.iter().enumerate() {
+            self.write_register(start + i as u8, *byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Driver structure for the device generic over its bus [Transport].
+///
+/// [Transport]: trait.Transport.html
+#[derive(Debug)]
+pub struct Device<T: Transport> {
+    /// Holds the bus transport.
+    transport: T,
+    /// true: read back and verify each command; false: write only.
+    verify: bool,
+}
+
+impl<T: Transport> Device<T> {
+    /// Constructor with bus transport.
+    ///
+    /// The device registers are initialized for 4-wire SPI or I²C use.
+    ///
+    /// ## Arguments
+    /// * `transport` - Bus transport used to talk to the device.
+    pub fn new(transport: T) -> AdxlResult<Self> {
+        let mut device = Self::new_uninit(transport);
+        device.init()?;
+        Ok(device)
+    }
+    /// Constructor which skips initializing the device registers.
+    pub(crate) fn new_uninit(transport: T) -> Self {
+        Device {
+            transport,
+            verify: false,
+        }
+    }
+    /// Re-initializes the device registers and then reads each one back.
+    ///
+    /// Same as [init()] but returns [AdxlError::VerifyMismatch] on the first
+    /// register which does not hold the written value, so a flaky bus can not
+    /// silently leave the device half configured.
+    /// The constructor uses the faster unverified [init()].
+    ///
+    /// [init()]: ../trait.Adxl345Writer.html#tymethod.init
+    /// [AdxlError::VerifyMismatch]: ../enum.AdxlError.html#variant.VerifyMismatch
+    pub fn init_verified(&mut self) -> Result {
+        self.init_registers_verified(false)
+    }
+    /// Consumes the device returning the bus transport.
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+    /// Enables or disables read-back verification of each command.
+    ///
+    /// When enabled every write, including all the typed setters, is followed
+    /// by a read of the same register and an [AdxlError::VerifyMismatch] is
+    /// returned if the values differ.
+    ///
+    /// ___Note:___ _This doubles the bus traffic of each write._
+    ///
+    /// [AdxlError::VerifyMismatch]: ../enum.AdxlError.html#variant.VerifyMismatch
+    pub fn set_verify(&mut self, verify: bool) {
+        self.verify = verify;
+    }
+    /// Access the bus transport.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+    /// Mutable access to the bus transport.
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+    /// Writes a contiguous range of registers in a single bus transaction.
+    ///
+    /// ## Arguments
+    /// * `start` - First register address to be written.
+    /// * `bytes` - Bytes of data to be written starting at `start`.
+    fn command_range(&mut self, start: u8, bytes: &[u8]) -> Result {
+        check_range(start, bytes.len())?;
+        log_trace!(
+            "write {} ({:#04x}) + {} = {:02x?}",
+            crate::logging::register_name(start),
+            start,
+            bytes.len(),
+            bytes
+        );
+        self.transport.write_burst(start, bytes)?;
+        if self.verify {
+            for (i, byte) in bytes.iter().enumerate() {
+                verify_register(self, start + i as u8, *byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Transport> Adxl345 for Device<T> {}
+impl<T: Transport> Adxl345Init for Device<T> {}
+
+impl<T: Transport> Adxl345Reader for Device<T> {
+    fn access(&self, register: u8) -> AdxlResult<u8> {
+        let byte = self.transport.read_register(register)?;
+        log_trace!(
+            "read {} ({:#04x}) = {:#04x}",
+            crate::logging::register_name(register),
+            register,
+            byte
+        );
+        Ok(byte)
+    }
+    fn acceleration(&self) -> AdxlResult<(i16, i16, i16)> {
+        let register = 0x32;
+        let buf = &mut [0u8; 6];
+        self.transport.read_burst(register, buf)?;
+        Ok(parse_sample(buf))
+    }
+    fn access_range(&self, start: u8, len: usize) -> AdxlResult<Vec<u8>> {
+        check_range(start, len)?;
+        let mut buf = vec![0u8; len];
+        self.transport.read_burst(start, &mut buf)?;
+        log_trace!(
+            "read {} ({:#04x}) + {} = {:02x?}",
+            crate::logging::register_name(start),
+            start,
+            len,
+            buf
+        );
+        Ok(buf)
+    }
+}
+
+impl<T: Transport> Adxl345Writer for Device<T> {
+    fn command(&mut self, register: u8, byte: u8) -> Result {
+        log_trace!(
+            "write {} ({:#04x}) = {:#04x}",
+            crate::logging::register_name(register),
+            register,
+            byte
+        );
+        self.transport.write_register(register, byte)?;
+        if self.verify {
+            verify_register(self, register, byte)?;
+        }
+        Ok(())
+    }
+    fn init(&mut self) -> Result {
+        log_debug!("Initializing device registers");
+        self.init_registers(false)
+    }
+    fn set_offset_adjustment<X, Y, Z>(&mut self, x: X, y: Y, z: Z) -> Result
+    where
+        X: Into<Option<i8>>,
+        Y: Into<Option<i8>>,
+        Z: Into<Option<i8>>,
+    {
+        match (x.into(), y.into(), z.into()) {
+            (Some(x), Some(y), Some(z)) => {
+                let register = 0x1e;
+                self.command_range(register, &[x as u8, y as u8, z as u8])
+            }
+            (x, y, z) => write_offsets(self, x, y, z),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory register file standing in for the device.
+    ///
+    /// Counts the bus transactions used to write to it.
+    struct Registers {
+        values: [u8; 0x40],
+        writes: usize,
+        bursts: usize,
+    }
+
+    impl Registers {
+        fn new() -> Self {
+            Registers {
+                values: [0; 0x40],
+                writes: 0,
+                bursts: 0,
+            }
+        }
+    }
+
+    impl Transport for Registers {
+        fn read_register(&self, register: u8) -> AdxlResult<u8> {
+            Ok(self.values[register as usize])
+        }
+        fn write_register(&mut self, register: u8, byte: u8) -> Result {
+            self.writes += 1;
+            self.values[register as usize] = byte;
+            Ok(())
+        }
+        fn read_burst(&self, start: u8, buf: &mut [u8]) -> Result {
+            let start = start as usize;
+            buf.copy_from_slice(&self.values[start..start + buf.len()]);
+            Ok(())
+        }
+        fn write_burst(&mut self, start: u8, bytes: &[u8]) -> Result {
+            self.bursts += 1;
+            let start = start as usize;
+            self.values[start..start + bytes.len()].copy_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn acceleration_and_fifo_match_parse_sample() {
+        let bytes = [0xff, 0x01, 0x00, 0xff, 0x34, 0x12];
+        let mut registers = Registers::new();
+        registers.values[0x32..0x38].copy_from_slice(&bytes);
+        // One fifo entry available.
+        registers.values[0x39] = 0x01;
+        let device = Device::new_uninit(registers);
+        let expected = parse_sample(&bytes);
+        assert_eq!(device.acceleration().unwrap(), expected);
+        let mut out = [(0, 0, 0); 4];
+        assert_eq!(device.read_fifo_into(&mut out).unwrap(), 1);
+        assert_eq!(out[0], expected);
+    }
+
+    #[test]
+    fn apply_rejects_invalid_fifo_before_writing() {
+        let mut device = Device::new_uninit(Registers::new());
+        let config = crate::Adxl345Config {
+            // Bypass mode with a watermark of 1.
+            fifo_control: 0x01.into(),
+            ..crate::Adxl345Config::sensible_default()
+        };
+        assert!(matches!(
+            config.apply(&mut device),
+            Err(crate::AdxlError::InvalidFifoConfig(1))
+        ));
+        let registers = device.transport();
+        assert_eq!((registers.bursts, registers.writes), (0, 0));
+    }
+
+    #[test]
+    fn dump_profile_clears_bypass_samples() {
+        let mut registers = Registers::new();
+        // Bypass mode with a stale watermark of 5.
+        registers.values[0x38] = 0x05;
+        let mut device = Device::new_uninit(registers);
+        let profile = device.dump_profile().unwrap();
+        assert_eq!(u8::from(profile.fifo_control), 0x00);
+        profile.apply(&mut device).unwrap();
+        assert_eq!(device.transport().values[0x38], 0x00);
+    }
+
+    #[test]
+    fn read_trigger_buffer_reads_only_retained_samples() {
+        let mut registers = Registers::new();
+        // Triggered with 10 entries while retaining 4 samples.
+        registers.values[0x38] = 0xc4;
+        registers.values[0x39] = 0x80 | 10;
+        let mut device = Device::new_uninit(registers);
+        assert_eq!(device.read_trigger_buffer().unwrap().len(), 4);
+        let registers = device.transport();
+        assert_eq!(registers.writes, 2);
+        assert_eq!(registers.values[0x38], 0xc4);
+    }
+
+    #[test]
+    fn read_trigger_buffer_rejects_invalid_fifo_before_writing() {
+        let mut registers = Registers::new();
+        // Triggered in trigger mode with 0 samples.
+        registers.values[0x38] = 0xc0;
+        registers.values[0x39] = 0x80 | 10;
+        let mut device = Device::new_uninit(registers);
+        assert!(matches!(
+            device.read_trigger_buffer(),
+            Err(crate::AdxlError::InvalidFifoConfig(0))
+        ));
+        assert_eq!(device.transport().writes, 0);
+    }
+
+    #[test]
+    fn offset_adjustment_uses_one_burst_for_all_axes() {
+        let mut device = Device::new_uninit(Registers::new());
+        device.set_offset_adjustment(1, -2, 3).unwrap();
+        let registers = device.transport();
+        assert_eq!((registers.bursts, registers.writes), (1, 0));
+        assert_eq!(registers.values[0x1e..0x21], [0x01, 0xfe, 0x03]);
+        device.set_offset_adjustment(4, None, 5).unwrap();
+        let registers = device.transport();
+        assert_eq!((registers.bursts, registers.writes), (1, 2));
+        assert_eq!(registers.values[0x1e..0x21], [0x04, 0xfe, 0x05]);
+    }
+
+    #[test]
+    fn paced_reader_new_rejects_invalid_rates() {
+        let device = Device::new_uninit(Registers::new());
+        for rate_hz in [0.0, -1.0, f64::NAN, f64::INFINITY, 1e-300, 1e10] {
+            assert!(matches!(
+                crate::PacedReader::new(&device, rate_hz),
+                Err(crate::AdxlError::InvalidRate(_))
+            ));
+        }
+        let paced = crate::PacedReader::new(&device, 100.0).unwrap();
+        assert_eq!(paced.period(), std::time::Duration::from_millis(10));
+    }
+}
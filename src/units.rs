@@ -0,0 +1,74 @@
+// MIT License
+//
+// Copyright © 2020-present, Michael Cummings <mgcummings@yahoo.com>.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Contains unit types which keep raw register counts and physical
+//! acceleration values apart.
+
+use crate::DataFormat;
+
+/// Raw acceleration data value exactly as read from a pair of data registers.
+///
+/// The meaning of the value depends on the [DataFormat] in effect when it was
+/// read so converting it requires one.
+///
+/// [DataFormat]: struct.DataFormat.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Counts(pub i16);
+
+impl Counts {
+    /// Converts into milli-g.
+    ///
+    /// ## Arguments
+    /// * `format` - Data format in effect when the counts were read.
+    pub fn to_milligravity(self, format: DataFormat) -> Milligravity {
+        let counts = format.sign_extend_if_needed(self.0);
+        Milligravity(counts as f64 * format.scale_factor() * 1000.0)
+    }
+}
+
+/// Acceleration in milli-g.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Milligravity(pub f64);
+
+impl Milligravity {
+    /// Converts into counts as they would be read with the given format.
+    ///
+    /// The value is rounded to the nearest count and saturates at the limits
+    /// of the format's resolution.
+    ///
+    /// ## Arguments
+    /// * `format` - Data format used for the scale factor, resolution, and
+    ///   justification.
+    pub fn to_counts(self, format: DataFormat) -> Counts {
+        let shift = 16 - format.resolution_bits();
+        let limit = (i16::MAX >> shift) as f64;
+        let counts = (self.0 / 1000.0 / format.scale_factor())
+            .round()
+            .clamp(-limit - 1.0, limit) as i16;
+        Counts(if format.justify() {
+            counts << shift
+        } else {
+            counts
+        })
+    }
+}